@@ -0,0 +1,53 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use la::matrix::*;
+use la::vector::*;
+use la::dynamic::DynMatrix;
+
+// cargo bench --bench mmul, compares the blocked kernel against the multiplication it replaced
+// and against Strassen with a cutoff of 64
+
+fn previous<const N: usize>(left: Matrix<f64, N, N>, right: Matrix<f64, N, N>) -> Matrix<f64, N, N> {
+    // the old mmul, a fresh row and column for every entry of the result (only right for square matrices)
+    Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| dot(left.get_row(i), right.get_column(j)))))
+}
+
+fn time(mut run: impl FnMut()) -> Duration {
+    // the fastest of enough runs to fill a fifth of a second
+    let start: Instant = Instant::now();
+    let mut best: Duration = Duration::MAX;
+    while start.elapsed() < Duration::from_millis(200) {
+        let lap: Instant = Instant::now();
+        run();
+        best = best.min(lap.elapsed());
+    }
+
+    best
+}
+
+fn report(name: &str, size: usize, duration: Duration, baseline: Duration) {
+    let flops: f64 = 2.0 * (size as f64).powi(3);
+    println!("{:<10} {:>5} {:>12.3?} {:>8.2} GFLOP/s {:>8.1}x", name, size, duration, flops / duration.as_secs_f64() / 1e9, baseline.as_secs_f64() / duration.as_secs_f64());
+}
+
+fn compare<const N: usize>() {
+    let a: Matrix<f64, N, N> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| ((i * 7 + j * 3) % 11) as f64 - 5.0)));
+    let b: Matrix<f64, N, N> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| ((i * 5 + j * 2) % 13) as f64 - 6.0)));
+    let (x, y): (DynMatrix<f64>, DynMatrix<f64>) = (DynMatrix::from(a), DynMatrix::from(b));
+
+    let baseline: Duration = time(|| { black_box(previous(black_box(a), black_box(b))); });
+    report("previous", N, baseline, baseline);
+    report("mmul", N, time(|| { black_box(mmul(black_box(a), black_box(b))); }), baseline);
+    report("dynamic", N, time(|| { black_box(&x * &y); }), baseline);
+    report("strassen", N, time(|| { black_box(strassen(black_box(a), black_box(b), 64)); }), baseline);
+}
+
+fn main() {
+    // Matrix lives on the stack, so the sizes stop well short of overflowing it
+    println!("{:<10} {:>5} {:>12} {:>16} {:>9}", "kernel", "size", "time", "rate", "speedup");
+    compare::<16>();
+    compare::<64>();
+    compare::<128>();
+    compare::<256>();
+}
@@ -0,0 +1,579 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::PathBuf;
+
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+
+use la::dynamic::DynMatrix;
+
+// an interactive calculator for working through exercises by hand,
+// every value is either a number or a runtime sized matrix of f64
+
+const HELP: &str = "type an expression to evaluate it, or name = expression to keep the result
+
+  A = [1 2; 3 4]      matrices are written row by row, rows split by ; or newlines
+  b = [5; 6]          a column vector is a matrix with one column
+  A * b + b           + - * / work like on paper, ^ raises to a power
+  A'                  transpose
+  A \\ b               the solution x of A x = b
+  -A / 2              scalars scale every entry
+  x = A \\ b;          a trailing ; keeps the result quiet
+
+functions: reduce rref rank det inv transpose trace eig eye zeros ones
+commands:  help [topic]  vars  clear [name]  history  !n  quit
+
+the last result is always available as ans, try help reduce or help rank";
+
+const TOPICS: [(&str, &str); 14] = [
+    ("reduce", "reduce(A) is the reduced row echelon form of A: every pivot is 1 and the only\nnonzero entry in its column, entries within rounding of zero count as zero"),
+    ("rref", "rref(A) is another name for reduce(A)"),
+    ("rank", "rank(A) is the number of pivots in reduce(A), the dimension of the column space"),
+    ("det", "det(A) is the determinant of a square matrix, zero exactly when A has no inverse"),
+    ("inv", "inv(A) is the inverse of a square matrix, an error when A is singular"),
+    ("transpose", "transpose(A), or A', swaps rows and columns"),
+    ("trace", "trace(A) is the sum of the diagonal of a square matrix"),
+    ("eig", "eig(A) is a column of the eigenvalues of a square matrix, in ascending order,\nan error when some are complex"),
+    ("eye", "eye(n) is the n by n identity matrix"),
+    ("zeros", "zeros(r, c) is an r by c matrix of zeros, zeros(n) is square"),
+    ("ones", "ones(r, c) is an r by c matrix of ones, ones(n) is square"),
+    ("solve", "A \\ b is the x with A x = b for a square A, b can have several columns"),
+    ("history", "history lists what was typed this session, !n runs line n again,\nthe arrow keys step through earlier sessions too"),
+    ("vars", "vars lists the variables, clear removes one or all of them")
+];
+
+const MAX_ENTRIES: usize = 1 << 24; // the largest matrix eye, zeros and ones will build
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Scalar(f64),
+    Matrix(DynMatrix<f64>)
+}
+
+#[derive(Debug)]
+struct Error {
+    span: Range<usize>,
+    message: String
+}
+
+fn error<T>(span: Range<usize>, message: String) -> Result<T, Error> {
+    Err(Error { span, message })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Literal(DynMatrix<f64>),
+    Operator(char) // one of + - * / \ ^ ' ( ) , =
+}
+
+fn lex(line: &str) -> Result<Vec<(Range<usize>, Token)>, Error> {
+    let bytes: &[u8] = line.as_bytes();
+    let mut result: Vec<(Range<usize>, Token)> = Vec::new();
+    let mut i: usize = 0;
+
+    while i < bytes.len() {
+        let start: usize = i;
+        match bytes[i] {
+            b' ' | b'\t' => i += 1,
+            b'0'..=b'9' | b'.' => {
+                while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                    i += 1;
+                }
+                if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                    // an exponent only when digits follow
+                    let mut j: usize = i + 1;
+                    if j < bytes.len() && (bytes[j] == b'+' || bytes[j] == b'-') {
+                        j += 1;
+                    }
+                    if j < bytes.len() && bytes[j].is_ascii_digit() {
+                        i = j;
+                        while i < bytes.len() && bytes[i].is_ascii_digit() {
+                            i += 1;
+                        }
+                    }
+                }
+                match line[start..i].parse() {
+                    Ok(value) => result.push((start..i, Token::Number(value))),
+                    Err(_) => return error(start..i, format!("invalid number '{}'", &line[start..i]))
+                }
+            },
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+                while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                    i += 1;
+                }
+                result.push((start..i, Token::Name(String::from(&line[start..i]))));
+            },
+            b'[' => {
+                // the whole bracketed literal goes to the matrix parser
+                let mut depth: usize = 0;
+                while i < bytes.len() {
+                    match bytes[i] {
+                        b'[' => depth += 1,
+                        b']' => depth -= 1,
+                        _ => ()
+                    }
+                    i += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                if depth > 0 {
+                    return error(start..line.len(), String::from("missing ']'"));
+                }
+                match line[start..i].parse::<DynMatrix<f64>>() {
+                    Ok(matrix) => result.push((start..i, Token::Literal(matrix))),
+                    Err(e) => return error((start + e.span.start)..(start + e.span.end), e.message)
+                }
+            },
+            b'+' | b'-' | b'*' | b'/' | b'\\' | b'^' | b'\'' | b'(' | b')' | b',' | b'=' => {
+                i += 1;
+                result.push((start..i, Token::Operator(bytes[start] as char)));
+            },
+            _ => {
+                let c: char = line[start..].chars().next().unwrap();
+                return error(start..(start + c.len_utf8()), format!("unexpected '{}'", c));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Scalar(_) => String::from("a scalar"),
+        Value::Matrix(matrix) => format!("a {}x{} matrix", matrix.rows(), matrix.columns())
+    }
+}
+
+fn square(matrix: &DynMatrix<f64>, span: &Range<usize>, what: &str) -> Result<(), Error> {
+    match matrix.is_square() {
+        true => Ok(()),
+        false => error(span.clone(), format!("{} needs a square matrix, found {}x{}", what, matrix.rows(), matrix.columns()))
+    }
+}
+
+fn add(left: Value, right: Value, negate: bool, span: Range<usize>) -> Result<Value, Error> {
+    let sign: f64 = if negate { -1.0 } else { 1.0 };
+    match (left, right) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a + sign * b)),
+        (Value::Matrix(a), Value::Matrix(b)) if (a.rows(), a.columns()) == (b.rows(), b.columns()) => {
+            Ok(Value::Matrix(if negate { &a - &b } else { &a + &b }))
+        },
+        (left, right) => error(span, format!("cannot {} {} and {}", if negate { "subtract" } else { "add" }, describe(&left), describe(&right)))
+    }
+}
+
+fn multiply(left: Value, right: Value, span: Range<usize>) -> Result<Value, Error> {
+    match (left, right) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a * b)),
+        (Value::Scalar(a), Value::Matrix(b)) | (Value::Matrix(b), Value::Scalar(a)) => Ok(Value::Matrix(&b * a)),
+        (Value::Matrix(a), Value::Matrix(b)) if a.columns() == b.rows() => Ok(Value::Matrix(&a * &b)),
+        (left, right) => error(span, format!("cannot multiply {} by {}, the inner dimensions differ", describe(&left), describe(&right)))
+    }
+}
+
+fn divide(left: Value, right: Value, span: Range<usize>) -> Result<Value, Error> {
+    match (left, right) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a / b)),
+        (Value::Matrix(a), Value::Scalar(b)) => Ok(Value::Matrix(&a / b)),
+        (_, Value::Matrix(_)) => error(span, String::from("cannot divide by a matrix, use \\ to solve a system"))
+    }
+}
+
+fn solve(left: Value, right: Value, span: Range<usize>) -> Result<Value, Error> {
+    // A \ B solves A X = B for every column of B at once
+    let (a, b) = match (left, right) {
+        (Value::Scalar(a), right) => return divide(right, Value::Scalar(a), span),
+        (Value::Matrix(a), Value::Matrix(b)) => (a, b),
+        (left, right) => return error(span, format!("cannot solve with {} on the left and {} on the right", describe(&left), describe(&right)))
+    };
+    square(&a, &span, "\\")?;
+    if a.rows() != b.rows() {
+        return error(span, format!("the right hand side needs {} rows, found {}", a.rows(), b.rows()));
+    }
+
+    match a.solve_columns(&b) {
+        Some(x) => Ok(Value::Matrix(x)),
+        None => error(span, String::from("the matrix is singular, the system has no unique solution"))
+    }
+}
+
+fn power(base: Value, exponent: Value, span: Range<usize>) -> Result<Value, Error> {
+    match (base, exponent) {
+        (Value::Scalar(a), Value::Scalar(b)) => Ok(Value::Scalar(a.powf(b))),
+        (Value::Matrix(a), Value::Scalar(n)) if n.fract() == 0.0 => {
+            square(&a, &span, "^")?;
+            if n.abs() > u32::MAX as f64 {
+                return error(span, format!("the power {} is too large, matrix powers go up to {}", n, u32::MAX));
+            }
+            match n >= 0.0 {
+                true => Ok(Value::Matrix(a.pow(n as u32))),
+                false => match a.inverse() {
+                    Some(inverse) => Ok(Value::Matrix(inverse.pow((-n) as u32))),
+                    None => error(span, String::from("the matrix is singular, negative powers need an inverse"))
+                }
+            }
+        },
+        (_, _) => error(span, String::from("matrices can only be raised to whole number powers"))
+    }
+}
+
+fn matrix_argument(arguments: &[(Range<usize>, Value)], index: usize, name: &str) -> Result<DynMatrix<f64>, Error> {
+    match &arguments[index] {
+        (_, Value::Matrix(matrix)) => Ok(matrix.clone()),
+        (span, value) => error(span.clone(), format!("{} needs a matrix, found {}", name, describe(value)))
+    }
+}
+
+fn size_argument(arguments: &[(Range<usize>, Value)], index: usize, name: &str) -> Result<usize, Error> {
+    match &arguments[index] {
+        (_, Value::Scalar(n)) if *n >= 0.0 && n.fract() == 0.0 && *n <= MAX_ENTRIES as f64 => Ok(*n as usize),
+        (span, _) => error(span.clone(), format!("{} needs a whole number size up to {}", name, MAX_ENTRIES))
+    }
+}
+
+fn call(name: &str, arguments: Vec<(Range<usize>, Value)>, span: Range<usize>) -> Result<Value, Error> {
+    let count: usize = match name {
+        "zeros" | "ones" => if arguments.len() == 1 { 1 } else { 2 },
+        "reduce" | "rref" | "rank" | "det" | "inv" | "transpose" | "trace" | "eig" | "eye" => 1,
+        _ => return error(span, format!("unknown function '{}', see help", name))
+    };
+    if arguments.len() != count {
+        return error(span, format!("{} takes {} argument{}, found {}", name, count, if count == 1 { "" } else { "s" }, arguments.len()));
+    }
+
+    match name {
+        "reduce" | "rref" => Ok(Value::Matrix(matrix_argument(&arguments, 0, name)?.rref())),
+        "rank" => Ok(Value::Scalar(matrix_argument(&arguments, 0, name)?.rank() as f64)),
+        "transpose" => Ok(Value::Matrix(matrix_argument(&arguments, 0, name)?.transpose())),
+        "det" | "inv" | "trace" | "eig" => {
+            let matrix: DynMatrix<f64> = matrix_argument(&arguments, 0, name)?;
+            square(&matrix, &span, name)?;
+            match name {
+                "det" => Ok(Value::Scalar(matrix.determinant())),
+                "trace" => Ok(Value::Scalar(matrix.trace())),
+                "inv" => match matrix.inverse() {
+                    Some(inverse) => Ok(Value::Matrix(inverse)),
+                    None => error(span, String::from("the matrix is singular"))
+                },
+                _ => {
+                    let values: Vec<f64> = match matrix.symmetric_eigen() {
+                        Some((values, _)) => values,
+                        None => {
+                            let values = matrix.eigenvalues();
+                            if let Some(z) = values.iter().find(|z| z.im != 0.0) {
+                                return error(span, format!("the eigenvalues are complex, for example {} {} {}i", z.re, if z.im < 0.0 { '-' } else { '+' }, z.im.abs()));
+                            }
+                            values.iter().map(|z| z.re).collect()
+                        }
+                    };
+                    Ok(Value::Matrix(DynMatrix::new(values.len(), 1, values)))
+                }
+            }
+        },
+        _ => {
+            let rows: usize = size_argument(&arguments, 0, name)?;
+            let columns: usize = if count == 2 { size_argument(&arguments, 1, name)? } else { rows };
+            if rows.checked_mul(columns).is_none_or(|entries| entries > MAX_ENTRIES) {
+                return error(span, format!("a {}x{} matrix is too large, the limit is {} entries", rows, columns, MAX_ENTRIES));
+            }
+            match name {
+                "eye" => Ok(Value::Matrix(DynMatrix::identity(rows))),
+                "zeros" => Ok(Value::Matrix(DynMatrix::zeros(rows, columns))),
+                _ => Ok(Value::Matrix(DynMatrix::new(rows, columns, vec![1.0; rows * columns])))
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Range<usize>, Token)>,
+    position: usize,
+    end: usize,
+    variables: &'a BTreeMap<String, Value>
+}
+
+impl Parser<'_> {
+    // expression := term (('+' | '-') term)*
+    // term       := unary (('*' | '/' | '\') unary)*
+    // unary      := '-' unary | power
+    // power      := postfix ('^' unary)?
+    // postfix    := primary '\''*
+    // primary    := number | matrix | name | name '(' arguments ')' | '(' expression ')'
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(_, token)| token)
+    }
+
+    fn span(&self) -> Range<usize> {
+        match self.tokens.get(self.position) {
+            Some((span, _)) => span.clone(),
+            None => self.end..self.end
+        }
+    }
+
+    fn start(&self) -> usize {
+        self.span().start
+    }
+
+    fn finish(&self, start: usize) -> Range<usize> {
+        // from start to the end of the last token used
+        start..self.tokens[..self.position].last().map_or(start, |(span, _)| span.end)
+    }
+
+    fn accept(&mut self, operator: char) -> bool {
+        if self.peek() == Some(&Token::Operator(operator)) {
+            self.position += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn expect(&mut self, operator: char) -> Result<(), Error> {
+        match self.accept(operator) {
+            true => Ok(()),
+            false => error(self.span(), format!("expected '{}'", operator))
+        }
+    }
+
+    fn expression(&mut self) -> Result<Value, Error> {
+        let start: usize = self.start();
+        let mut result: Value = self.term()?;
+        loop {
+            let negate: bool = match self.peek() {
+                Some(Token::Operator('+')) => false,
+                Some(Token::Operator('-')) => true,
+                _ => return Ok(result)
+            };
+            self.position += 1;
+            let right: Value = self.term()?;
+            result = add(result, right, negate, self.finish(start))?;
+        }
+    }
+
+    fn term(&mut self) -> Result<Value, Error> {
+        let start: usize = self.start();
+        let mut result: Value = self.unary()?;
+        loop {
+            let operator: char = match self.peek() {
+                Some(Token::Operator(c)) if ['*', '/', '\\'].contains(c) => *c,
+                _ => return Ok(result)
+            };
+            self.position += 1;
+            let right: Value = self.unary()?;
+            let span: Range<usize> = self.finish(start);
+            result = match operator {
+                '*' => multiply(result, right, span)?,
+                '/' => divide(result, right, span)?,
+                _ => solve(result, right, span)?
+            };
+        }
+    }
+
+    fn unary(&mut self) -> Result<Value, Error> {
+        if self.accept('-') {
+            let value: Value = self.unary()?;
+            return multiply(Value::Scalar(-1.0), value, 0..0);
+        }
+
+        self.power()
+    }
+
+    fn power(&mut self) -> Result<Value, Error> {
+        let start: usize = self.start();
+        let base: Value = self.postfix()?;
+        if self.accept('^') {
+            let exponent: Value = self.unary()?;
+            return power(base, exponent, self.finish(start));
+        }
+
+        Ok(base)
+    }
+
+    fn postfix(&mut self) -> Result<Value, Error> {
+        let mut result: Value = self.primary()?;
+        while self.accept('\'') {
+            if let Value::Matrix(matrix) = result {
+                result = Value::Matrix(matrix.transpose());
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn primary(&mut self) -> Result<Value, Error> {
+        let span: Range<usize> = self.span();
+        let token: Token = match self.tokens.get(self.position) {
+            Some((_, token)) => token.clone(),
+            None => return error(span, String::from("expected a value"))
+        };
+        self.position += 1;
+
+        match token {
+            Token::Number(value) => Ok(Value::Scalar(value)),
+            Token::Literal(matrix) => Ok(Value::Matrix(matrix)),
+            Token::Operator('(') => {
+                let value: Value = self.expression()?;
+                self.expect(')')?;
+                Ok(value)
+            },
+            Token::Name(name) if self.accept('(') => {
+                let mut arguments: Vec<(Range<usize>, Value)> = Vec::new();
+                if !self.accept(')') {
+                    loop {
+                        let start: usize = self.start();
+                        let value: Value = self.expression()?;
+                        arguments.push((self.finish(start), value));
+                        if self.accept(')') {
+                            break;
+                        }
+                        self.expect(',')?;
+                    }
+                }
+                call(&name, arguments, self.finish(span.start))
+            },
+            Token::Name(name) => match self.variables.get(&name) {
+                Some(value) => Ok(value.clone()),
+                None => error(span, format!("'{}' is not defined", name))
+            },
+            Token::Operator(c) => error(span, format!("unexpected '{}'", c))
+        }
+    }
+}
+
+fn evaluate(line: &str, variables: &BTreeMap<String, Value>) -> Result<(Option<String>, Value), Error> {
+    // an optional "name =" in front of an expression
+    let tokens: Vec<(Range<usize>, Token)> = lex(line)?;
+    let target: Option<String> = match (tokens.first(), tokens.get(1)) {
+        (Some((_, Token::Name(name))), Some((_, Token::Operator('=')))) => Some(name.clone()),
+        _ => None
+    };
+
+    let mut parser = Parser { tokens, position: if target.is_some() { 2 } else { 0 }, end: line.len(), variables };
+    let value: Value = parser.expression()?;
+    if parser.position < parser.tokens.len() {
+        return error(parser.span().start..line.len(), String::from("unexpected text after the expression"));
+    }
+
+    Ok((target, value))
+}
+
+fn show(name: &str, value: &Value) -> String {
+    // rounded to ten places so 0.1 + 0.2 reads as 0.3, adding zero turns -0 into 0,
+    // large values have no digits left there and scaling them up could overflow
+    let clean = |x: f64| if x.abs() < 1e5 { (x * 1e10).round() / 1e10 + 0.0 } else { x };
+    match value {
+        Value::Scalar(x) => format!("{} = {}", name, clean(*x)),
+        Value::Matrix(matrix) => {
+            let rows: Vec<Vec<f64>> = matrix.to_rows().into_iter().map(|row| row.into_iter().map(clean).collect()).collect();
+            format!("{} =\n{}", name, DynMatrix::from_rows(rows, matrix.columns()).unwrap())
+        }
+    }
+}
+
+fn report(line: &str, e: &Error) {
+    // the line again with the offending part underlined
+    let start: usize = line[..e.span.start].chars().count();
+    let width: usize = line[e.span.clone()].chars().count().max(1);
+    eprintln!("  {}\n  {}{}\nerror: {}", line, " ".repeat(start), "^".repeat(width), e.message);
+}
+
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".la_history"))
+}
+
+fn main() {
+    let mut editor: DefaultEditor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(e) => {
+            eprintln!("la-repl: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let path: Option<PathBuf> = history_path();
+    if let Some(path) = &path {
+        let _ = editor.load_history(path);
+    }
+
+    println!("la {}, type help for a tour or quit to leave", env!("CARGO_PKG_VERSION"));
+    let mut variables: BTreeMap<String, Value> = BTreeMap::new();
+    let mut session: Vec<String> = Vec::new();
+
+    loop {
+        let mut line: String = match editor.readline(">> ") {
+            Ok(line) => line.trim().to_string(),
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("la-repl: {}", e);
+                break;
+            }
+        };
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(number) = line.strip_prefix('!') {
+            match number.parse::<usize>().ok().and_then(|n| session.get(n.wrapping_sub(1))) {
+                Some(earlier) => {
+                    line = earlier.clone();
+                    println!("{}", line);
+                },
+                None => {
+                    eprintln!("error: no line {} in this session's history", number);
+                    continue;
+                }
+            }
+        }
+        let _ = editor.add_history_entry(line.as_str());
+        session.push(line.clone());
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            ["quit"] | ["exit"] => break,
+            ["help"] => println!("{}", HELP),
+            ["help", topic] => match TOPICS.iter().find(|(name, _)| name == topic) {
+                Some((_, text)) => println!("{}", text),
+                None => println!("no help for '{}', the topics are {}", topic, TOPICS.map(|(name, _)| name).join(" "))
+            },
+            ["vars"] => {
+                for (name, value) in variables.iter() {
+                    println!("  {:<8} {}", name, describe(value));
+                }
+            },
+            ["clear"] => variables.clear(),
+            ["clear", name] => {
+                if variables.remove(*name).is_none() {
+                    eprintln!("error: '{}' is not defined", name);
+                }
+            },
+            ["history"] => {
+                for (i, entry) in session.iter().enumerate() {
+                    println!("{:>4}  {}", i + 1, entry);
+                }
+            },
+            _ => {
+                let quiet: bool = line.ends_with(';');
+                let expression: &str = line.trim_end_matches(';');
+                match evaluate(expression, &variables) {
+                    Ok((target, value)) => {
+                        let name: String = target.unwrap_or(String::from("ans"));
+                        if !quiet {
+                            println!("{}", show(&name, &value));
+                        }
+                        variables.insert(name, value);
+                    },
+                    Err(e) => report(expression, &e)
+                }
+            }
+        }
+    }
+
+    if let Some(path) = &path {
+        let _ = editor.save_history(path);
+    }
+}
@@ -0,0 +1,335 @@
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use num::complex::Complex;
+use serde_json::{json, Value};
+
+use la::dynamic::DynMatrix;
+use la::polynomial::Polynomial;
+use la::display::Delimiter;
+
+// la <command> [FILE|-] [options], quick checks from the shell without writing a program
+
+const USAGE: &str = "usage: la <command> [FILE|-] [options]
+
+commands:
+  det           determinant of a square matrix
+  inverse       inverse of a square matrix
+  rref          reduced row echelon form
+  rank          rank
+  transpose     transpose
+  eigen         eigenvalues, with eigenvectors for symmetric matrices
+  solve         solution of A x = b, b from --rhs or the last column of the input
+  roots         roots of a polynomial such as \"x^2 - 3x + 2\"
+  derivative    derivative of a polynomial
+
+options:
+  -e TEXT               read the input from TEXT instead of a file
+  --format FORMAT       auto, text, csv, tsv or mtx (default auto)
+  --delimiter C         the column separator for csv (default ,)
+  --header              skip the first line of csv input
+  --rhs FILE            the right hand side for solve, in the same formats
+  --output OUTPUT       text, json or latex (default text)
+  --precision N         round the results to N digits
+
+without FILE, or with -, the input is read from stdin";
+
+const COMMANDS: [&str; 9] = ["det", "inverse", "rref", "rank", "transpose", "eigen", "solve", "roots", "derivative"];
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Format {
+    Auto,
+    Text,
+    Csv(char),
+    MatrixMarket
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Output {
+    Text,
+    Json,
+    Latex
+}
+
+struct Options {
+    command: String,
+    input: Option<String>, // a path, None for stdin
+    expression: Option<String>,
+    format: Format,
+    header: bool,
+    rhs: Option<String>,
+    output: Output,
+    precision: Option<usize>
+}
+
+enum Answer {
+    Scalar(f64),
+    Count(usize),
+    Matrix(DynMatrix<f64>),
+    Vector(Vec<f64>),
+    Complex(Vec<Complex<f64>>),
+    Eigen(Vec<f64>, DynMatrix<f64>),
+    Polynomial(Polynomial<f64>)
+}
+
+fn usage(message: &str) -> ! {
+    eprintln!("la: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("la: {}", message);
+    process::exit(1);
+}
+
+fn parse_options(arguments: Vec<String>) -> Options {
+    let mut arguments = arguments.into_iter();
+    let command: String = match arguments.next() {
+        Some(command) if command == "-h" || command == "--help" => {
+            println!("{}", USAGE);
+            process::exit(0);
+        },
+        Some(command) if COMMANDS.contains(&command.as_str()) => command,
+        Some(command) => usage(&format!("unknown command '{}'", command)),
+        None => usage("missing command")
+    };
+
+    let mut options = Options { command, input: None, expression: None, format: Format::Auto, header: false, rhs: None, output: Output::Text, precision: None };
+    let mut delimiter: Option<char> = None;
+    while let Some(argument) = arguments.next() {
+        let mut value = |name: &str| arguments.next().unwrap_or_else(|| usage(&format!("{} needs a value", name)));
+        match argument.as_str() {
+            "-e" => options.expression = Some(value("-e")),
+            "--format" => options.format = match value("--format").as_str() {
+                "auto" => Format::Auto,
+                "text" => Format::Text,
+                "csv" => Format::Csv(','),
+                "tsv" => Format::Csv('\t'),
+                "mtx" => Format::MatrixMarket,
+                other => usage(&format!("unknown format '{}'", other))
+            },
+            "--delimiter" => {
+                let text: String = value("--delimiter");
+                let mut chars = text.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => delimiter = Some(c),
+                    _ => usage("the delimiter has to be a single character")
+                }
+            },
+            "--header" => options.header = true,
+            "--rhs" => options.rhs = Some(value("--rhs")),
+            "--output" => options.output = match value("--output").as_str() {
+                "text" => Output::Text,
+                "json" => Output::Json,
+                "latex" => Output::Latex,
+                other => usage(&format!("unknown output '{}'", other))
+            },
+            "--precision" => options.precision = Some(value("--precision").parse().unwrap_or_else(|_| usage("the precision has to be a number"))),
+            "-" => options.input = None,
+            _ if argument.starts_with('-') => usage(&format!("unknown option '{}'", argument)),
+            _ if options.input.is_none() => options.input = Some(argument),
+            _ => usage(&format!("unexpected argument '{}'", argument))
+        }
+    }
+
+    if let Some(delimiter) = delimiter {
+        options.format = match options.format {
+            Format::Auto | Format::Csv(_) => Format::Csv(delimiter),
+            _ => usage("--delimiter only applies to csv")
+        };
+    }
+
+    options
+}
+
+fn read_source(path: Option<&str>) -> String {
+    let result = match path {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e)),
+        None => {
+            let mut text: String = String::new();
+            io::stdin().read_to_string(&mut text).map(|_| text).map_err(|e| format!("stdin: {}", e))
+        }
+    };
+
+    result.unwrap_or_else(|message| fail(message))
+}
+
+fn read_matrix(text: &str, format: Format, header: bool) -> DynMatrix<f64> {
+    // auto picks Matrix Market from the banner, text from a leading '[' and csv otherwise
+    let format: Format = match format {
+        Format::Auto if text.starts_with("%%MatrixMarket") => Format::MatrixMarket,
+        Format::Auto if text.trim_start().starts_with('[') => Format::Text,
+        Format::Auto if text.contains('\t') => Format::Csv('\t'),
+        Format::Auto => Format::Csv(','),
+        format => format
+    };
+
+    let result = match format {
+        Format::Text => text.parse().map_err(|e| format!("{}", e)),
+        Format::MatrixMarket => DynMatrix::from_matrix_market(text).map_err(|e| format!("{}", e)),
+        Format::Csv(delimiter) => DynMatrix::from_csv(text, delimiter, header).map_err(|e| format!("{}", e)),
+        Format::Auto => unreachable!()
+    };
+
+    result.unwrap_or_else(|message| fail(message))
+}
+
+fn square(matrix: &DynMatrix<f64>, command: &str) {
+    if !matrix.is_square() {
+        fail(format!("{} needs a square matrix, found {}x{}", command, matrix.rows(), matrix.columns()));
+    }
+}
+
+fn run(options: &Options) -> Answer {
+    let text: String = match &options.expression {
+        Some(expression) => expression.clone(),
+        None => read_source(options.input.as_deref())
+    };
+
+    if options.command == "roots" || options.command == "derivative" {
+        let polynomial: Polynomial<f64> = text.trim().parse().unwrap_or_else(|e| fail(format!("{}", e)));
+        return match options.command.as_str() {
+            "roots" => Answer::Complex(polynomial.roots()),
+            _ => Answer::Polynomial(polynomial.derivative())
+        };
+    }
+
+    let matrix: DynMatrix<f64> = read_matrix(&text, options.format, options.header);
+    match options.command.as_str() {
+        "det" => {
+            square(&matrix, "det");
+            Answer::Scalar(matrix.determinant())
+        },
+        "inverse" => {
+            square(&matrix, "inverse");
+            Answer::Matrix(matrix.inverse().unwrap_or_else(|| fail(String::from("the matrix is singular"))))
+        },
+        "rref" => Answer::Matrix(matrix.rref()),
+        "rank" => Answer::Count(matrix.rank()),
+        "transpose" => Answer::Matrix(matrix.transpose()),
+        "eigen" => {
+            square(&matrix, "eigen");
+            match matrix.symmetric_eigen() {
+                Some((values, vectors)) => Answer::Eigen(values, vectors),
+                None => Answer::Complex(matrix.eigenvalues())
+            }
+        },
+        "solve" => {
+            // without --rhs the last column is the right hand side
+            let (system, right): (DynMatrix<f64>, Vec<f64>) = match &options.rhs {
+                Some(path) => {
+                    let rhs: DynMatrix<f64> = read_matrix(&read_source(Some(path)), options.format, options.header);
+                    let right: Vec<f64> = match (rhs.rows(), rhs.columns()) {
+                        (_, 1) => (0..rhs.rows()).map(|i| rhs[i][0]).collect(),
+                        (1, _) => rhs[0].to_vec(),
+                        _ => fail(String::from("the right hand side has to be a single row or column"))
+                    };
+                    (matrix, right)
+                },
+                None => {
+                    if matrix.columns() == 0 {
+                        fail(String::from("solve needs an augmented matrix or --rhs"));
+                    }
+                    let n: usize = matrix.columns() - 1;
+                    let rows: Vec<Vec<f64>> = (0..matrix.rows()).map(|i| matrix[i][..n].to_vec()).collect();
+                    let right: Vec<f64> = (0..matrix.rows()).map(|i| matrix[i][n]).collect();
+                    (DynMatrix::from_rows(rows, n).unwrap(), right)
+                }
+            };
+
+            square(&system, "solve");
+            if right.len() != system.rows() {
+                fail(format!("the right hand side needs {} entries, found {}", system.rows(), right.len()));
+            }
+            Answer::Vector(system.solve(&right).unwrap_or_else(|| fail(String::from("the system has no unique solution"))))
+        },
+        _ => unreachable!()
+    }
+}
+
+fn round(value: f64, precision: Option<usize>) -> f64 {
+    // adding zero turns -0 into 0, values with no digits left to drop at that scale are kept as is,
+    // scaling them up could overflow
+    match precision {
+        Some(digits) => {
+            let scale: f64 = 10_f64.powi(digits.min(300) as i32);
+            let scaled: f64 = value * scale;
+            if scaled.abs() < 2_f64.powi(52) { scaled.round() / scale + 0.0 } else { value }
+        },
+        None => value
+    }
+}
+
+fn complex_text(z: &Complex<f64>) -> String {
+    if z.im == 0.0 {
+        format!("{}", z.re)
+    } else if z.im < 0.0 {
+        format!("{} - {}i", z.re, -z.im)
+    } else {
+        format!("{} + {}i", z.re, z.im)
+    }
+}
+
+fn column_latex(values: &[String]) -> String {
+    format!("\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}", values.join(" \\\\\n"))
+}
+
+fn render(answer: Answer, output: Output, precision: Option<usize>) -> String {
+    let r = |value: f64| round(value, precision);
+    let rounded = |matrix: &DynMatrix<f64>| {
+        let rows: Vec<Vec<f64>> = matrix.to_rows().into_iter().map(|row| row.into_iter().map(r).collect()).collect();
+        DynMatrix::from_rows(rows, matrix.columns()).unwrap()
+    };
+    let complex = |values: &[Complex<f64>]| -> Vec<Complex<f64>> { values.iter().map(|z| Complex::new(r(z.re), r(z.im))).collect() };
+
+    match (answer, output) {
+        (Answer::Scalar(value), Output::Json) => json!(r(value)).to_string(),
+        (Answer::Scalar(value), _) => format!("{}", r(value)),
+        (Answer::Count(count), Output::Json) => json!(count).to_string(),
+        (Answer::Count(count), _) => format!("{}", count),
+        (Answer::Matrix(matrix), Output::Text) => format!("{}", rounded(&matrix)),
+        (Answer::Matrix(matrix), Output::Json) => json!(rounded(&matrix).to_rows()).to_string(),
+        (Answer::Matrix(matrix), Output::Latex) => rounded(&matrix).to_latex(Delimiter::Brackets),
+        (Answer::Vector(values), output) => {
+            let values: Vec<f64> = values.into_iter().map(r).collect();
+            match output {
+                Output::Text => values.iter().map(|x| x.to_string()).collect::<Vec<String>>().join("\n"),
+                Output::Json => json!(values).to_string(),
+                Output::Latex => column_latex(&values.iter().map(|x| x.to_string()).collect::<Vec<String>>())
+            }
+        },
+        (Answer::Complex(values), output) => {
+            let values: Vec<Complex<f64>> = complex(&values);
+            match output {
+                Output::Text => values.iter().map(complex_text).collect::<Vec<String>>().join("\n"),
+                Output::Json => Value::from(values.iter().map(|z| json!({"re": z.re, "im": z.im})).collect::<Vec<Value>>()).to_string(),
+                Output::Latex => column_latex(&values.iter().map(complex_text).collect::<Vec<String>>())
+            }
+        },
+        (Answer::Eigen(values, vectors), output) => {
+            let values: Vec<f64> = values.into_iter().map(r).collect();
+            let vectors: DynMatrix<f64> = rounded(&vectors);
+            let list: Vec<String> = values.iter().map(|x| x.to_string()).collect();
+            match output {
+                Output::Text => format!("values:\n{}\nvectors:\n{}", list.join("\n"), vectors),
+                Output::Json => json!({"values": values, "vectors": vectors.to_rows()}).to_string(),
+                Output::Latex => format!("{}\n{}", column_latex(&list), vectors.to_latex(Delimiter::Brackets))
+            }
+        },
+        (Answer::Polynomial(polynomial), output) => {
+            let polynomial: Polynomial<f64> = Polynomial::from(polynomial.coefficients().iter().map(|&x| r(x)).collect::<Vec<f64>>());
+            match output {
+                Output::Text => format!("{}", polynomial),
+                Output::Json => json!(polynomial.coefficients()).to_string(),
+                Output::Latex => polynomial.to_latex()
+            }
+        }
+    }
+}
+
+fn main() {
+    let options: Options = parse_options(std::env::args().skip(1).collect());
+    let answer: Answer = run(&options);
+    println!("{}", render(answer, options.output, options.precision));
+}
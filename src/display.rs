@@ -0,0 +1,201 @@
+use std::fmt;
+
+use num::Num;
+
+use crate::vector::Vector;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::dynamic::DynMatrix;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Delimiter {
+    Brackets, // bmatrix
+    Parentheses // pmatrix
+}
+impl Delimiter {
+    fn environment(&self) -> &'static str {
+        match self {
+            Delimiter::Brackets => "bmatrix",
+            Delimiter::Parentheses => "pmatrix"
+        }
+    }
+}
+
+fn entry<F: fmt::Display>(value: &F, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value)
+    }
+}
+
+fn write_rows<F: fmt::Display>(f: &mut fmt::Formatter, rows: &[&[F]]) -> fmt::Result {
+    // one bracketed row per line, {:.3} sets the precision and {:8} the narrowest column,
+    // every entry is right aligned to the widest one in its column
+    if rows.is_empty() {
+        return write!(f, "[]");
+    }
+
+    let (precision, width) = (f.precision(), f.width().unwrap_or(0));
+    let mut cells: Vec<Vec<String>> = rows.iter().map(|row| row.iter().map(|value| entry(value, precision)).collect()).collect();
+    for j in 0..rows[0].len() {
+        let widest: usize = cells.iter().map(|row| row[j].chars().count()).max().unwrap_or(0).max(width);
+        for row in cells.iter_mut() {
+            row[j] = format!("{:>width$}", row[j], width = widest);
+        }
+    }
+
+    for (i, row) in cells.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        write!(f, "[{}]", row.join("  "))?;
+    }
+
+    Ok(())
+}
+
+fn latex_rows<F: fmt::Display>(rows: &[&[F]], delimiter: Delimiter) -> String {
+    let mut result: String = format!("\\begin{{{}}}\n", delimiter.environment());
+    for (i, row) in rows.iter().enumerate() {
+        let entries: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+        result += &entries.join(" & ");
+        result += if i + 1 < rows.len() { " \\\\\n" } else { "\n" };
+    }
+    result += &format!("\\end{{{}}}", delimiter.environment());
+
+    result
+}
+
+impl<F: Num + Copy + fmt::Display, const R: usize, const C: usize> fmt::Display for Matrix<F, R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<&[F]> = self.iter().map(|row| row.as_slice()).collect();
+        write_rows(f, &rows)
+    }
+}
+
+impl<F: Num + Copy + fmt::Display> fmt::Display for DynMatrix<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let rows: Vec<&[F]> = (0..self.rows()).map(|i| &self[i]).collect();
+        write_rows(f, &rows)
+    }
+}
+
+impl<F: Num + Copy + fmt::Display, const R: usize> fmt::Display for Vector<F, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // on one line, precision and width apply to every entry
+        let width: usize = f.width().unwrap_or(0);
+        let entries: Vec<String> = self.iter().map(|value| format!("{:>width$}", entry(value, f.precision()), width = width)).collect();
+        write!(f, "[{}]", entries.join(", "))
+    }
+}
+
+fn terms<F: Num + Copy + fmt::Display>(polynomial: &Polynomial<F>, precision: Option<usize>, power: fn(usize) -> String) -> String {
+    // highest degree first, zero terms left out and unit coefficients written as just the power of x
+    let coefficients: &[F] = polynomial.coefficients();
+    if coefficients.is_empty() {
+        return entry(&F::zero(), precision);
+    }
+
+    let mut result: String = String::new();
+    for k in (0..coefficients.len()).rev() {
+        let coefficient: F = coefficients[k];
+        if coefficient == F::zero() {
+            continue;
+        }
+
+        let mut text: String = entry(&coefficient, precision);
+        let negative: bool = text.starts_with('-');
+        if negative {
+            text.remove(0);
+        }
+        if text.contains(['+', '-']) {
+            // compound coefficients such as complex numbers
+            text = format!("({})", text);
+        }
+
+        let unit: bool = coefficient == F::one() || coefficient == F::zero() - F::one();
+        let term: String = match (k, unit) {
+            (0, _) => text,
+            (_, true) => power(k),
+            (_, false) => format!("{}{}", text, power(k))
+        };
+
+        match (result.is_empty(), negative) {
+            (true, true) => result += &format!("-{}", term),
+            (true, false) => result += &term,
+            (false, true) => result += &format!(" - {}", term),
+            (false, false) => result += &format!(" + {}", term)
+        }
+    }
+
+    result
+}
+
+impl<F: Num + Copy + fmt::Display> fmt::Display for Polynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // 3x^2 - x + 1, the width pads the whole expression
+        let power = |k: usize| if k == 1 { String::from("x") } else { format!("x^{}", k) };
+        let text: String = terms(self, f.precision(), power);
+        write!(f, "{:>width$}", text, width = f.width().unwrap_or(0))
+    }
+}
+
+impl<F: Num + Copy + fmt::Display, const R: usize, const C: usize> Matrix<F, R, C> {
+    pub fn to_latex(&self, delimiter: Delimiter) -> String {
+        let rows: Vec<&[F]> = self.iter().map(|row| row.as_slice()).collect();
+        latex_rows(&rows, delimiter)
+    }
+
+    pub fn to_markdown(&self, header: Option<&[&str]>) -> String {
+        // a right aligned table, markdown needs a header row so it is left blank without names
+        let names: Vec<String> = match header {
+            Some(names) => {
+                assert_eq!(names.len(), C, "the header needs one name per column");
+                names.iter().map(|name| name.replace('|', "\\|")).collect()
+            },
+            None => vec![String::new(); C]
+        };
+
+        let mut result: String = format!("| {} |\n", names.join(" | "));
+        result += &format!("|{}\n", "---:|".repeat(C));
+        for i in 0..R {
+            let row: Vec<String> = self[i].iter().map(|value| value.to_string()).collect();
+            result += &format!("| {} |\n", row.join(" | "));
+        }
+
+        result
+    }
+}
+
+impl<F: Num + Copy + fmt::Display> DynMatrix<F> {
+    pub fn to_latex(&self, delimiter: Delimiter) -> String {
+        let rows: Vec<&[F]> = (0..self.rows()).map(|i| &self[i]).collect();
+        latex_rows(&rows, delimiter)
+    }
+}
+
+impl<F: Num + Copy + fmt::Display, const R: usize> Vector<F, R> {
+    pub fn to_latex(&self, delimiter: Delimiter) -> String {
+        // a column vector
+        let entries: Vec<String> = self.iter().map(|value| value.to_string()).collect();
+        format!("\\begin{{{}}}\n{}\n\\end{{{}}}", delimiter.environment(), entries.join(" \\\\\n"), delimiter.environment())
+    }
+
+    pub fn to_markdown(&self, header: Option<&str>) -> String {
+        // a single column table
+        let mut result: String = format!("| {} |\n|---:|\n", header.unwrap_or("").replace('|', "\\|"));
+        for value in self.iter() {
+            result += &format!("| {} |\n", value);
+        }
+
+        result
+    }
+}
+
+impl<F: Num + Copy + fmt::Display> Polynomial<F> {
+    pub fn to_latex(&self) -> String {
+        // the expression only, without surrounding $
+        let power = |k: usize| if k == 1 { String::from("x") } else { format!("x^{{{}}}", k) };
+        terms(self, None, power)
+    }
+}
@@ -0,0 +1,408 @@
+use std::fmt;
+use std::error::Error;
+use std::ops;
+
+use num::{Float, Num};
+use num::complex::Complex;
+
+use crate::matrix::{Matrix, solve_rows, jacobi_eigen, hessenberg_eigen, multiply, strassen_into, nan_last};
+use crate::parallel::{Element, chunks};
+use crate::polynomial::Polynomial;
+
+// Matrix fixes its size at compile time, which does not work for matrices that come from
+// files or user input. DynMatrix keeps the size at runtime and converts to and from Matrix.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DimensionMismatch {
+    pub expected: (usize, usize), // (rows, columns)
+    pub found: (usize, usize)
+}
+impl fmt::Display for DimensionMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected a {}x{} matrix, found {}x{}", self.expected.0, self.expected.1, self.found.0, self.found.1)
+    }
+}
+impl Error for DimensionMismatch {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DynMatrix<F: Num + Copy> {
+    rows: usize,
+    columns: usize,
+    data: Vec<F> // row major
+}
+impl<F: Num + Copy> ops::Index<usize> for DynMatrix<F> {
+    // a row, so m[i][j] works like it does for Matrix
+    type Output = [F];
+    fn index(&self, row: usize) -> &Self::Output {
+        &self.data[(row * self.columns)..((row + 1) * self.columns)]
+    }
+}
+impl<F: Num + Copy> ops::IndexMut<usize> for DynMatrix<F> {
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        &mut self.data[(row * self.columns)..((row + 1) * self.columns)]
+    }
+}
+impl<F: Num + Copy + Element> ops::Add<&DynMatrix<F>> for &DynMatrix<F> {
+    type Output = DynMatrix<F>;
+
+    fn add(self, right: &DynMatrix<F>) -> DynMatrix<F> {
+        self.entrywise(right, |a, b| a + b)
+    }
+}
+impl<F: Num + Copy + Element> ops::Sub<&DynMatrix<F>> for &DynMatrix<F> {
+    type Output = DynMatrix<F>;
+
+    fn sub(self, right: &DynMatrix<F>) -> DynMatrix<F> {
+        self.entrywise(right, |a, b| a - b)
+    }
+}
+impl<F: Num + Copy + Element> ops::Mul<F> for &DynMatrix<F> {
+    type Output = DynMatrix<F>;
+
+    fn mul(self, scalar: F) -> DynMatrix<F> {
+        self.entrywise(self, |a, _| a * scalar)
+    }
+}
+impl<F: Num + Copy + Element> ops::Div<F> for &DynMatrix<F> {
+    type Output = DynMatrix<F>;
+
+    fn div(self, scalar: F) -> DynMatrix<F> {
+        self.entrywise(self, |a, _| a / scalar)
+    }
+}
+impl<F: Num + Copy + Element> ops::Mul<&DynMatrix<F>> for &DynMatrix<F> {
+    type Output = DynMatrix<F>;
+
+    fn mul(self, right: &DynMatrix<F>) -> DynMatrix<F> {
+        assert_eq!(self.columns, right.rows, "the inner dimensions have to agree");
+        let mut result: DynMatrix<F> = DynMatrix::zeros(self.rows, right.columns);
+        multiply(&self.data, &right.data, &mut result.data, (self.rows, self.columns, right.columns));
+
+        result
+    }
+}
+impl<F: Num + Copy, const R: usize, const C: usize> From<Matrix<F, R, C>> for DynMatrix<F> {
+    fn from(matrix: Matrix<F, R, C>) -> Self {
+        DynMatrix { rows: R, columns: C, data: matrix.iter().flatten().copied().collect() }
+    }
+}
+impl<F: Num + Copy, const R: usize, const C: usize> TryFrom<DynMatrix<F>> for Matrix<F, R, C> {
+    type Error = DimensionMismatch;
+
+    fn try_from(matrix: DynMatrix<F>) -> Result<Self, Self::Error> {
+        if (matrix.rows, matrix.columns) != (R, C) {
+            return Err(DimensionMismatch { expected: (R, C), found: (matrix.rows, matrix.columns) });
+        }
+
+        let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+        for i in 0..R {
+            result[i].copy_from_slice(&matrix[i]);
+        }
+
+        Ok(Matrix::from(result))
+    }
+}
+
+impl<F: Num + Copy> DynMatrix<F> {
+    pub fn new(rows: usize, columns: usize, data: Vec<F>) -> DynMatrix<F> {
+        assert!(rows.checked_mul(columns) == Some(data.len()), "a {}x{} matrix cannot hold {} entries", rows, columns, data.len());
+        DynMatrix { rows, columns, data }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<F>>, columns: usize) -> Result<DynMatrix<F>, DimensionMismatch> {
+        // columns is only used when there are no rows to tell it
+        let columns: usize = rows.first().map_or(columns, |row| row.len());
+        let mut data: Vec<F> = Vec::new();
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != columns {
+                return Err(DimensionMismatch { expected: (rows.len(), columns), found: (i + 1, row.len()) });
+            }
+            data.extend_from_slice(row);
+        }
+
+        Ok(DynMatrix { rows: rows.len(), columns, data })
+    }
+
+    pub fn zeros(rows: usize, columns: usize) -> DynMatrix<F> {
+        let count: usize = rows.checked_mul(columns).unwrap_or_else(|| panic!("a {}x{} matrix has too many entries", rows, columns));
+        DynMatrix { rows, columns, data: vec![F::zero(); count] }
+    }
+
+    pub fn identity(size: usize) -> DynMatrix<F> {
+        let mut result: DynMatrix<F> = DynMatrix::zeros(size, size);
+        for i in 0..size {
+            result[i][i] = F::one();
+        }
+
+        result
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    pub fn to_rows(&self) -> Vec<Vec<F>> {
+        (0..self.rows).map(|i| self[i].to_vec()).collect()
+    }
+
+    pub fn transpose(&self) -> DynMatrix<F> {
+        let mut result: DynMatrix<F> = DynMatrix::zeros(self.columns, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                result[j][i] = self[i][j];
+            }
+        }
+
+        result
+    }
+
+    pub fn trace(&self) -> F {
+        assert!(self.is_square(), "the trace needs a square matrix");
+        (0..self.rows).fold(F::zero(), |sum, i| sum + self[i][i])
+    }
+
+    pub fn pow(&self, exponent: u32) -> DynMatrix<F> where F: Element {
+        // by repeated squaring
+        assert!(self.is_square(), "only square matrices have powers");
+        let mut result: DynMatrix<F> = DynMatrix::identity(self.rows);
+        let mut base: DynMatrix<F> = self.clone();
+        let mut exponent: u32 = exponent;
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                result = &result * &base;
+            }
+            base = &base * &base;
+            exponent /= 2;
+        }
+
+        result
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        for i in 0..self.rows {
+            for j in 0..i {
+                if self[i][j] != self[j][i] {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<F: Num + Copy + Element> DynMatrix<F> {
+    fn entrywise(&self, right: &DynMatrix<F>, operation: impl Fn(F, F) -> F + Element) -> DynMatrix<F> {
+        assert_eq!((self.rows, self.columns), (right.rows, right.columns), "the dimensions have to agree");
+        let mut result: DynMatrix<F> = DynMatrix::zeros(self.rows, self.columns);
+        chunks(&mut result.data, self.columns, self.rows * self.columns, |i, row| {
+            for j in 0..row.len() {
+                row[j] = operation(self[i][j], right[i][j]);
+            }
+        });
+
+        result
+    }
+
+    pub fn strassen(&self, right: &DynMatrix<F>, cutoff: usize) -> DynMatrix<F> {
+        // the product by Strassen-Winograd for square matrices of the same size, see matrix::strassen
+        assert!(self.is_square() && right.is_square() && self.rows == right.rows, "Strassen multiplication needs two square matrices of the same size");
+        let mut result: DynMatrix<F> = DynMatrix::zeros(self.rows, self.rows);
+        strassen_into(&self.data, &right.data, &mut result.data, self.rows, cutoff);
+
+        result
+    }
+}
+
+impl<F: Float + Element> DynMatrix<F> {
+    fn tolerance(&self) -> F {
+        // entries this small after elimination count as zero
+        let largest: F = self.data.iter().fold(F::zero(), |m, x| m.max(x.abs()));
+        F::epsilon() * largest * F::from(self.rows.max(self.columns).max(1)).unwrap()
+    }
+
+    pub fn rref(&self) -> DynMatrix<F> {
+        // Gauss-Jordan with partial pivoting, unlike Matrix::reduce near zero pivots are skipped
+        let tolerance: F = self.tolerance();
+        let mut result: DynMatrix<F> = self.clone();
+        let mut row: usize = 0;
+
+        for col in 0..self.columns {
+            if row >= self.rows {
+                break;
+            }
+
+            // the largest magnitude first, a NaN is never preferred over a number
+            let pivot_row: usize = (row..self.rows).min_by(|&a, &b| nan_last(-result[a][col].abs(), -result[b][col].abs())).unwrap();
+            if result[pivot_row][col].abs() <= tolerance {
+                for i in row..self.rows {
+                    result[i][col] = F::zero();
+                }
+                continue;
+            }
+
+            for j in 0..self.columns {
+                let (a, b) = (result[row][j], result[pivot_row][j]);
+                result[row][j] = b;
+                result[pivot_row][j] = a;
+            }
+
+            let scale: F = result[row][col];
+            for j in col..self.columns {
+                result[row][j] = result[row][j] / scale;
+            }
+
+            let pivot: Vec<F> = result[row].to_vec();
+            chunks(&mut result.data, self.columns, self.rows * (self.columns - col), |i, other| {
+                if i != row {
+                    let scale: F = other[col];
+                    for j in col..other.len() {
+                        other[j] = other[j] - (scale * pivot[j]);
+                    }
+                    other[col] = F::zero();
+                }
+            });
+
+            row += 1;
+        }
+
+        result
+    }
+
+    pub fn rank(&self) -> usize {
+        let reduced: DynMatrix<F> = self.rref();
+        (0..self.rows).filter(|&i| reduced[i].iter().any(|&x| x != F::zero())).count()
+    }
+
+    pub fn determinant(&self) -> F {
+        // LU with partial pivoting
+        assert!(self.is_square(), "the determinant needs a square matrix");
+        let n: usize = self.rows;
+        let mut a: DynMatrix<F> = self.clone();
+        let mut result: F = F::one();
+
+        for col in 0..n {
+            let pivot_row: usize = (col..n).min_by(|&x, &y| nan_last(-a[x][col].abs(), -a[y][col].abs())).unwrap();
+            if a[pivot_row][col] == F::zero() {
+                return F::zero();
+            }
+            if pivot_row != col {
+                for j in 0..n {
+                    let (x, y) = (a[col][j], a[pivot_row][j]);
+                    a[col][j] = y;
+                    a[pivot_row][j] = x;
+                }
+                result = F::zero() - result;
+            }
+
+            result = result * a[col][col];
+            for i in (col + 1)..n {
+                let scale: F = a[i][col] / a[col][col];
+                for j in col..n {
+                    a[i][j] = a[i][j] - (scale * a[col][j]);
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn inverse(&self) -> Option<DynMatrix<F>> {
+        // None for singular matrices, within the rank tolerance
+        assert!(self.is_square(), "only square matrices have inverses");
+        self.solve_columns(&DynMatrix::identity(self.rows))
+    }
+
+    pub fn solve_columns(&self, right: &DynMatrix<F>) -> Option<DynMatrix<F>> {
+        // the X with A X = B, one system per column of B solved in a single elimination,
+        // None if A is singular
+        assert!(self.is_square(), "solve needs a square matrix");
+        assert_eq!(right.rows, self.rows, "the right hand side needs one row per row of the matrix");
+        let (n, k): (usize, usize) = (self.rows, right.columns);
+
+        let mut augmented: DynMatrix<F> = DynMatrix::zeros(n, n + k);
+        for i in 0..n {
+            augmented[i][..n].copy_from_slice(&self[i]);
+            augmented[i][n..].copy_from_slice(&right[i]);
+        }
+
+        // the left block only reduces to the identity when every column has a pivot
+        let reduced: DynMatrix<F> = augmented.rref();
+        let mut result: DynMatrix<F> = DynMatrix::zeros(n, k);
+        for i in 0..n {
+            for j in 0..n {
+                if reduced[i][j] != if i == j { F::one() } else { F::zero() } {
+                    return None;
+                }
+            }
+            result[i].copy_from_slice(&reduced[i][n..]);
+        }
+
+        Some(result)
+    }
+
+    pub fn solve(&self, right: &[F]) -> Option<Vec<F>> {
+        // the unique x with A x = b, None if A is singular
+        assert!(self.is_square(), "solve needs a square matrix");
+        assert_eq!(right.len(), self.rows, "the right hand side needs one entry per row");
+
+        let system: Vec<Vec<F>> = (0..self.rows).map(|i| {
+            let mut row: Vec<F> = self[i].to_vec();
+            row.push(right[i]);
+            row
+        }).collect();
+
+        solve_rows(system)
+    }
+
+    pub fn characteristic_polynomial(&self) -> Polynomial<F> {
+        // det(x I - A) by the Faddeev-LeVerrier recurrence, monic of degree n
+        assert!(self.is_square(), "the characteristic polynomial needs a square matrix");
+        let n: usize = self.rows;
+
+        let mut coefficients: Vec<F> = vec![F::zero(); n + 1];
+        coefficients[n] = F::one();
+        let mut m: DynMatrix<F> = DynMatrix::zeros(n, n);
+        for k in 1..=n {
+            // M_k = A M_{k-1} + c_{n-k+1} I, c_{n-k} = -tr(A M_k) / k
+            let mut next: DynMatrix<F> = self * &m;
+            for i in 0..n {
+                next[i][i] = next[i][i] + coefficients[n - k + 1];
+            }
+            m = next;
+
+            let trace: F = (self * &m).trace();
+            coefficients[n - k] = F::zero() - (trace / F::from(k).unwrap());
+        }
+
+        Polynomial::from(coefficients)
+    }
+
+    pub fn eigenvalues(&self) -> Vec<Complex<F>> {
+        // by QR iteration on the matrix, symmetric matrices do better with symmetric_eigen
+        assert!(self.is_square(), "eigenvalues need a square matrix");
+        hessenberg_eigen(self.to_rows())
+    }
+
+    pub fn symmetric_eigen(&self) -> Option<(Vec<F>, DynMatrix<F>)> {
+        // eigenvalues ascending and eigenvectors as columns, None unless the matrix is symmetric
+        if !self.is_symmetric() {
+            return None;
+        }
+
+        let (values, vectors) = jacobi_eigen(self.to_rows());
+        Some((values, DynMatrix::from_rows(vectors, self.columns).unwrap()))
+    }
+}
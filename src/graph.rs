@@ -1,6 +1,23 @@
+use num::Num;
 use crate::matrix::Matrix;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+mod walks;
+mod paths;
+mod traversal;
+mod spectral;
+mod generators;
+mod formats;
+mod flow;
+mod spanning;
+mod algebra;
+mod isomorphism;
+
+pub use paths::{ShortestPaths, AllPairsShortestPaths, NegativeCycle};
+pub use traversal::{Dfs, Bfs, Cycle};
+pub use formats::GraphFormatError;
+pub use flow::MaximumFlow;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Graph<const N: usize> ([[u8; N]; N]);
 impl<const N: usize> From<[[u8; N]; N]> for Graph<N> {
     fn from(array: [[u8; N]; N]) -> Self {
@@ -21,4 +38,145 @@ impl<const N: usize> Into<Matrix<u8, N, N>> for Graph<N> {
     fn into(self) -> Matrix<u8, N, N> {
         Matrix::from(self.0)
     }
-}
\ No newline at end of file
+}
+
+impl<const N: usize> Graph<N> {
+    pub fn empty() -> Graph<N> {
+        Graph([[0; N]; N])
+    }
+
+    pub fn from_edges(edges: &[(usize, usize)]) -> Graph<N> {
+        // directed, a repeated pair becomes a parallel edge
+        let mut result: Graph<N> = Graph::empty();
+        for &(from, to) in edges {
+            result.add_edge(from, to);
+        }
+
+        result
+    }
+
+    pub fn undirected_from_edges(edges: &[(usize, usize)]) -> Graph<N> {
+        let mut result: Graph<N> = Graph::empty();
+        for &(from, to) in edges {
+            result.add_undirected_edge(from, to);
+        }
+
+        result
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize) {
+        self.0[from][to] = self.0[from][to].checked_add(1).expect("too many parallel edges for u8");
+    }
+
+    pub fn add_undirected_edge(&mut self, first: usize, second: usize) {
+        self.add_edge(first, second);
+        if first != second {
+            self.add_edge(second, first);
+        }
+    }
+
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> bool {
+        // removes one copy of the edge, returns whether there was one
+        if self.0[from][to] == 0 {
+            return false;
+        }
+
+        self.0[from][to] -= 1;
+        true
+    }
+
+    pub fn remove_undirected_edge(&mut self, first: usize, second: usize) -> bool {
+        let removed: bool = self.remove_edge(first, second);
+        if removed && first != second {
+            self.remove_edge(second, first);
+        }
+
+        removed
+    }
+
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.0[from][to] != 0
+    }
+
+    pub fn is_undirected(&self) -> bool {
+        for i in 0..N {
+            for j in (i + 1)..N {
+                if self.0[i][j] != self.0[j][i] {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn neighbors(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        // out neighbours, in increasing order
+        (0..N).filter(move |&next| self.0[vertex][next] != 0)
+    }
+
+    pub fn in_neighbors(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..N).filter(move |&previous| self.0[previous][vertex] != 0)
+    }
+
+    pub fn edges(&self) -> Vec<(usize, usize)> {
+        // every directed edge once, parallel edges included
+        let mut result: Vec<(usize, usize)> = Vec::new();
+        for i in 0..N {
+            for j in 0..N {
+                for _ in 0..self.0[i][j] {
+                    result.push((i, j));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WeightedGraph<W: Num + Copy, const N: usize> ([[Option<W>; N]; N]); // None where there is no edge
+impl<W: Num + Copy, const N: usize> From<[[Option<W>; N]; N]> for WeightedGraph<W, N> {
+    fn from(array: [[Option<W>; N]; N]) -> Self {
+        WeightedGraph(array)
+    }
+}
+impl<W: Num + Copy, const N: usize> Into<[[Option<W>; N]; N]> for WeightedGraph<W, N> {
+    fn into(self) -> [[Option<W>; N]; N] {
+        self.0
+    }
+}
+impl<W: Num + Copy, const N: usize> From<Matrix<W, N, N>> for WeightedGraph<W, N> {
+    fn from(matrix: Matrix<W, N, N>) -> Self {
+        // zero entries are read as missing edges
+        let mut array: [[Option<W>; N]; N] = [[None; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                if matrix[i][j] != W::zero() {
+                    array[i][j] = Some(matrix[i][j]);
+                }
+            }
+        }
+
+        WeightedGraph(array)
+    }
+}
+impl<W: Num + Copy + From<u8>, const N: usize> From<Graph<N>> for WeightedGraph<W, N> {
+    fn from(graph: Graph<N>) -> Self {
+        let mut array: [[Option<W>; N]; N] = [[None; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                if graph.0[i][j] != 0 {
+                    array[i][j] = Some(W::from(graph.0[i][j]));
+                }
+            }
+        }
+
+        WeightedGraph(array)
+    }
+}
+impl<W: Num + Copy, const N: usize> WeightedGraph<W, N> {
+    pub fn weight(&self, from: usize, to: usize) -> Option<W> {
+        self.0[from][to]
+    }
+}
@@ -0,0 +1,117 @@
+use crate::graph::Graph;
+
+impl<const N: usize> Graph<N> {
+    pub fn complement(&self) -> Graph<N> {
+        // simple complement, no self loops
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            for j in 0..N {
+                if i != j && self.0[i][j] == 0 {
+                    result.0[i][j] = 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn union(&self, other: &Graph<N>) -> Graph<N> {
+        // entrywise max, so parallel edges are not added together
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            for j in 0..N {
+                result.0[i][j] = self.0[i][j].max(other.0[i][j]);
+            }
+        }
+
+        result
+    }
+
+    pub fn intersection(&self, other: &Graph<N>) -> Graph<N> {
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            for j in 0..N {
+                result.0[i][j] = self.0[i][j].min(other.0[i][j]);
+            }
+        }
+
+        result
+    }
+
+    pub fn line_graph<const M: usize>(&self) -> Graph<M> { // :(
+        // Graph<{number of edges}> can't be written, so M has to be given and match at runtime.
+        // An undirected graph gives one vertex per edge {i, j} with i < j, adjacent when they share an endpoint,
+        // anything else gives the line digraph with (u, v) -> (v, w).
+        let undirected: bool = self.is_undirected();
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for i in 0..N {
+            let start: usize = if undirected { i + 1 } else { 0 };
+            for j in start..N {
+                if self.0[i][j] != 0 {
+                    edges.push((i, j));
+                }
+            }
+        }
+        assert_eq!(edges.len(), M, "the line graph has one vertex per edge");
+
+        let mut result: Graph<M> = Graph::empty();
+        for a in 0..M {
+            for b in 0..M {
+                if a == b {
+                    continue;
+                }
+
+                let ((u, v), (x, y)) = (edges[a], edges[b]);
+                let adjacent: bool = match undirected {
+                    true => u == x || u == y || v == x || v == y,
+                    false => v == x
+                };
+                if adjacent {
+                    result.0[a][b] = 1;
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn cartesian_product<const M: usize, const P: usize>(&self, other: &Graph<M>) -> Graph<P> { // :(
+        // vertex (i, j) is numbered i * M + j, and moves along one factor at a time
+        const { assert!((N * M) == P) }
+
+        let mut result: Graph<P> = Graph::empty();
+        for i in 0..N {
+            for j in 0..M {
+                for k in 0..M {
+                    result.0[(i * M) + j][(i * M) + k] = other.0[j][k];
+                }
+                for k in 0..N {
+                    // added rather than written, so self loops from both factors land on the diagonal
+                    let entry: &mut u8 = &mut result.0[(i * M) + j][(k * M) + j];
+                    *entry = entry.checked_add(self.0[i][k]).expect("too many parallel edges for u8");
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn tensor_product<const M: usize, const P: usize>(&self, other: &Graph<M>) -> Graph<P> { // :(
+        // (i, j) ~ (k, l) when i ~ k and j ~ l, which is the Kronecker product of the adjacency matrices,
+        // edge counts multiply and like add_edge this panics when a product does not fit in u8
+        const { assert!((N * M) == P) }
+
+        let mut result: Graph<P> = Graph::empty();
+        for i in 0..N {
+            for j in 0..M {
+                for k in 0..N {
+                    for l in 0..M {
+                        result.0[(i * M) + j][(k * M) + l] = self.0[i][k].checked_mul(other.0[j][l]).expect("too many parallel edges for u8");
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
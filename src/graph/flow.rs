@@ -0,0 +1,235 @@
+use std::collections::VecDeque;
+
+use crate::graph::Graph;
+use crate::matrix::Matrix;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MaximumFlow<const N: usize> {
+    value: u64,
+    flow: [[u8; N]; N],
+    source_side: [bool; N],
+    capacity: [[u8; N]; N]
+}
+impl<const N: usize> MaximumFlow<N> {
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+
+    pub fn flow(&self) -> Matrix<u8, N, N> {
+        // flow along each edge, never more than its capacity
+        Matrix::from(self.flow)
+    }
+
+    pub fn source_side(&self) -> [bool; N] {
+        // the minimum cut, vertices still reachable from the source in the residual graph
+        self.source_side
+    }
+
+    pub fn cut_edges(&self) -> Vec<(usize, usize)> {
+        // edges from the source side to the sink side, their capacities add up to the flow value
+        let mut result: Vec<(usize, usize)> = Vec::new();
+        for i in 0..N {
+            for j in 0..N {
+                if self.source_side[i] && !self.source_side[j] && self.capacity[i][j] != 0 {
+                    result.push((i, j));
+                }
+            }
+        }
+
+        result
+    }
+}
+
+type Solver = fn(&[Vec<i64>], usize, usize) -> Vec<Vec<i64>>;
+
+// The solvers work on runtime sized arrays so the matching network can add its own source and sink.
+// Flow is kept skew symmetric, flow[u][v] == -flow[v][u], which lets antiparallel edges share a residual.
+
+fn edmonds_karp(capacity: &[Vec<i64>], source: usize, sink: usize) -> Vec<Vec<i64>> {
+    // shortest augmenting paths by breadth first search
+    let n: usize = capacity.len();
+    let mut flow: Vec<Vec<i64>> = vec![vec![0; n]; n];
+
+    loop {
+        let mut parent: Vec<Option<usize>> = vec![None; n];
+        parent[source] = Some(source);
+        let mut queue: VecDeque<usize> = VecDeque::from([source]);
+        while let Some(u) = queue.pop_front() {
+            for v in 0..n {
+                if parent[v].is_none() && capacity[u][v] - flow[u][v] > 0 {
+                    parent[v] = Some(u);
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        if parent[sink].is_none() {
+            return flow;
+        }
+
+        // bottleneck along the path, then push it through
+        let mut bottleneck: i64 = i64::MAX;
+        let mut v: usize = sink;
+        while v != source {
+            let u: usize = parent[v].unwrap();
+            bottleneck = bottleneck.min(capacity[u][v] - flow[u][v]);
+            v = u;
+        }
+
+        let mut v: usize = sink;
+        while v != source {
+            let u: usize = parent[v].unwrap();
+            flow[u][v] += bottleneck;
+            flow[v][u] -= bottleneck;
+            v = u;
+        }
+    }
+}
+
+fn push_relabel(capacity: &[Vec<i64>], source: usize, sink: usize) -> Vec<Vec<i64>> {
+    // FIFO push relabel
+    let n: usize = capacity.len();
+    let mut flow: Vec<Vec<i64>> = vec![vec![0; n]; n];
+    let mut height: Vec<usize> = vec![0; n];
+    let mut excess: Vec<i64> = vec![0; n];
+    let mut active: VecDeque<usize> = VecDeque::new();
+
+    height[source] = n;
+    for v in 0..n {
+        if capacity[source][v] > 0 {
+            flow[source][v] += capacity[source][v];
+            flow[v][source] -= capacity[source][v];
+            excess[v] += capacity[source][v];
+            excess[source] -= capacity[source][v];
+            if v != sink {
+                active.push_back(v);
+            }
+        }
+    }
+
+    while let Some(u) = active.pop_front() {
+        // discharge u completely
+        while excess[u] > 0 {
+            let mut pushed: bool = false;
+            for v in 0..n {
+                let residual: i64 = capacity[u][v] - flow[u][v];
+                if residual > 0 && height[u] == height[v] + 1 {
+                    let amount: i64 = excess[u].min(residual);
+                    flow[u][v] += amount;
+                    flow[v][u] -= amount;
+                    excess[u] -= amount;
+                    excess[v] += amount;
+                    if v != source && v != sink && excess[v] == amount {
+                        active.push_back(v);
+                    }
+
+                    pushed = true;
+                    if excess[u] == 0 {
+                        break;
+                    }
+                }
+            }
+
+            if !pushed {
+                // relabel to one above the lowest residual neighbour
+                let mut lowest: usize = usize::MAX;
+                for v in 0..n {
+                    if capacity[u][v] - flow[u][v] > 0 {
+                        lowest = lowest.min(height[v]);
+                    }
+                }
+                height[u] = lowest + 1;
+            }
+        }
+    }
+
+    flow
+}
+
+fn reachable(capacity: &[Vec<i64>], flow: &[Vec<i64>], source: usize) -> Vec<bool> {
+    let n: usize = capacity.len();
+    let mut seen: Vec<bool> = vec![false; n];
+    seen[source] = true;
+    let mut stack: Vec<usize> = vec![source];
+    while let Some(u) = stack.pop() {
+        for v in 0..n {
+            if !seen[v] && capacity[u][v] - flow[u][v] > 0 {
+                seen[v] = true;
+                stack.push(v);
+            }
+        }
+    }
+
+    seen
+}
+
+impl<const N: usize> Graph<N> {
+    fn capacities(&self) -> Vec<Vec<i64>> {
+        self.0.iter().map(|row| row.iter().map(|&c| i64::from(c)).collect()).collect()
+    }
+
+    fn maximum_flow(&self, source: usize, sink: usize, solver: Solver) -> MaximumFlow<N> {
+        assert!(source != sink, "source and sink must differ");
+
+        let capacity: Vec<Vec<i64>> = self.capacities();
+        let net: Vec<Vec<i64>> = solver(&capacity, source, sink);
+        let seen: Vec<bool> = reachable(&capacity, &net, source);
+
+        let mut flow: [[u8; N]; N] = [[0; N]; N];
+        let mut source_side: [bool; N] = [false; N];
+        for i in 0..N {
+            source_side[i] = seen[i];
+            for j in 0..N {
+                // net flow is positive in at most one direction of an antiparallel pair
+                flow[i][j] = net[i][j].max(0) as u8;
+            }
+        }
+
+        let value: i64 = (0..N).map(|v| net[source][v]).sum();
+
+        MaximumFlow { value: value as u64, flow, source_side, capacity: self.0 }
+    }
+
+    pub fn edmonds_karp(&self, source: usize, sink: usize) -> MaximumFlow<N> {
+        // entries of the adjacency array are read as edge capacities
+        self.maximum_flow(source, sink, edmonds_karp)
+    }
+
+    pub fn push_relabel(&self, source: usize, sink: usize) -> MaximumFlow<N> {
+        self.maximum_flow(source, sink, push_relabel)
+    }
+
+    pub fn maximum_matching(&self) -> Option<Vec<(usize, usize)>> {
+        // unit capacity flow from a new source through the two sides of the bipartition to a new sink,
+        // returns (left, right) pairs, or None if the graph is not bipartite
+        let side: [bool; N] = self.bipartition()?;
+        let (source, sink): (usize, usize) = (N, N + 1);
+
+        let mut capacity: Vec<Vec<i64>> = vec![vec![0; N + 2]; N + 2];
+        for u in 0..N {
+            if side[u] {
+                capacity[u][sink] = 1;
+                continue;
+            }
+
+            capacity[source][u] = 1;
+            for v in 0..N {
+                if side[v] && (self.0[u][v] != 0 || self.0[v][u] != 0) {
+                    capacity[u][v] = 1;
+                }
+            }
+        }
+
+        let flow: Vec<Vec<i64>> = edmonds_karp(&capacity, source, sink);
+        let mut result: Vec<(usize, usize)> = Vec::new();
+        for u in 0..N {
+            for v in 0..N {
+                if !side[u] && side[v] && flow[u][v] > 0 {
+                    result.push((u, v));
+                }
+            }
+        }
+
+        Some(result)
+    }
+}
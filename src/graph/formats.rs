@@ -0,0 +1,384 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::HashMap;
+
+use crate::graph::Graph;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphFormatError {
+    Parse { line: usize, message: String }, // lines count from 1
+    SizeMismatch { expected: usize, found: usize } // Graph<N> is fixed size, so the file has to agree with N
+}
+impl fmt::Display for GraphFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GraphFormatError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            GraphFormatError::SizeMismatch { expected, found } => write!(f, "expected a graph with {} vertices, found {}", expected, found)
+        }
+    }
+}
+impl Error for GraphFormatError {}
+
+fn parse_error(line: usize, message: String) -> GraphFormatError {
+    GraphFormatError::Parse { line, message }
+}
+
+fn parse_number<T: std::str::FromStr>(token: &str, line: usize) -> Result<T, GraphFormatError> {
+    token.parse().map_err(|_| parse_error(line, format!("expected a number, found '{}'", token)))
+}
+
+fn add_edges<const N: usize>(graph: &mut Graph<N>, from: usize, to: usize, count: u8, line: usize) -> Result<(), GraphFormatError> {
+    if from >= N || to >= N {
+        return Err(parse_error(line, format!("vertex {} is out of range for a graph with {} vertices", from.max(to), N)));
+    }
+
+    graph.0[from][to] = graph.0[from][to].checked_add(count).ok_or_else(|| parse_error(line, String::from("too many parallel edges for u8")))?;
+    Ok(())
+}
+
+impl<const N: usize> Graph<N> {
+    pub fn to_dot(&self) -> String {
+        // undirected graphs are written once per edge with --, anything else as a digraph
+        let undirected: bool = self.is_undirected();
+        let mut result: String = String::from(if undirected { "graph G {\n" } else { "digraph G {\n" });
+
+        for i in 0..N {
+            result += &format!("    {};\n", i);
+        }
+
+        for i in 0..N {
+            let start: usize = if undirected { i } else { 0 };
+            for j in start..N {
+                for _ in 0..self.0[i][j] {
+                    match undirected {
+                        true => result += &format!("    {} -- {};\n", i, j),
+                        false => result += &format!("    {} -> {};\n", i, j)
+                    }
+                }
+            }
+        }
+
+        result += "}\n";
+        result
+    }
+
+    pub fn to_edge_list(&self) -> String {
+        // one directed edge "from to" per line, parallel edges repeated
+        let mut result: String = String::new();
+        for (from, to) in self.edges() {
+            result += &format!("{} {}\n", from, to);
+        }
+
+        result
+    }
+
+    pub fn from_edge_list(text: &str) -> Result<Graph<N>, GraphFormatError> {
+        // "from to" pairs separated by whitespace or commas, # starts a comment
+        let mut result: Graph<N> = Graph::empty();
+        for (index, line) in text.lines().enumerate() {
+            let line_number: usize = index + 1;
+            let content: &str = line.split('#').next().unwrap().trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            let tokens: Vec<&str> = content.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()).collect();
+            if tokens.len() != 2 {
+                return Err(parse_error(line_number, format!("expected two vertices, found {} fields", tokens.len())));
+            }
+
+            let from: usize = parse_number(tokens[0], line_number)?;
+            let to: usize = parse_number(tokens[1], line_number)?;
+            add_edges(&mut result, from, to, 1, line_number)?;
+        }
+
+        Ok(result)
+    }
+
+    pub fn to_csv(&self) -> String {
+        // the adjacency array, one row per line
+        let mut result: String = String::new();
+        for i in 0..N {
+            let row: Vec<String> = self.0[i].iter().map(|entry| entry.to_string()).collect();
+            result += &row.join(",");
+            result += "\n";
+        }
+
+        result
+    }
+
+    pub fn from_csv(text: &str) -> Result<Graph<N>, GraphFormatError> {
+        let mut result: Graph<N> = Graph::empty();
+        let mut rows: usize = 0;
+        for (index, line) in text.lines().enumerate() {
+            let line_number: usize = index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(',').map(|field| field.trim()).collect();
+            if fields.len() != N {
+                return Err(parse_error(line_number, format!("expected {} fields, found {}", N, fields.len())));
+            }
+            if rows >= N {
+                return Err(parse_error(line_number, format!("expected {} rows, found more", N)));
+            }
+
+            for j in 0..N {
+                result.0[rows][j] = parse_number(fields[j], line_number)?;
+            }
+            rows += 1;
+        }
+
+        if rows != N {
+            return Err(GraphFormatError::SizeMismatch { expected: N, found: rows });
+        }
+
+        Ok(result)
+    }
+
+    pub fn to_matrix_market(&self) -> String {
+        // coordinate format with 1-based indices and the multiplicity as the value
+        let mut entries: Vec<String> = Vec::new();
+        for i in 0..N {
+            for j in 0..N {
+                if self.0[i][j] != 0 {
+                    entries.push(format!("{} {} {}", i + 1, j + 1, self.0[i][j]));
+                }
+            }
+        }
+
+        let mut result: String = String::from("%%MatrixMarket matrix coordinate integer general\n");
+        result += &format!("{} {} {}\n", N, N, entries.len());
+        for entry in entries {
+            result += &entry;
+            result += "\n";
+        }
+
+        result
+    }
+
+    pub fn from_matrix_market(text: &str) -> Result<Graph<N>, GraphFormatError> {
+        // coordinate integer or pattern, general or symmetric
+        let mut lines = text.lines().enumerate();
+
+        let header: &str = match lines.next() {
+            Some((_, header)) => header,
+            None => return Err(parse_error(1, String::from("missing %%MatrixMarket header")))
+        };
+        let banner: Vec<String> = header.split_whitespace().map(|word| word.to_lowercase()).collect();
+        if banner.len() != 5 || banner[0] != "%%matrixmarket" || banner[1] != "matrix" || banner[2] != "coordinate" {
+            return Err(parse_error(1, String::from("expected '%%MatrixMarket matrix coordinate <field> <symmetry>'")));
+        }
+
+        let pattern: bool = match banner[3].as_str() {
+            "integer" => false,
+            "pattern" => true,
+            field => return Err(parse_error(1, format!("unsupported field '{}' for a graph", field)))
+        };
+        let symmetric: bool = match banner[4].as_str() {
+            "general" => false,
+            "symmetric" => true,
+            symmetry => return Err(parse_error(1, format!("unsupported symmetry '{}'", symmetry)))
+        };
+
+        let mut result: Graph<N> = Graph::empty();
+        let mut size_read: bool = false;
+        let (mut entries, mut found): (usize, usize) = (0, 0);
+        let mut last_line: usize = 1;
+        for (index, line) in lines {
+            let line_number: usize = index + 1;
+            let content: &str = line.trim();
+            if content.is_empty() || content.starts_with('%') {
+                continue;
+            }
+
+            let tokens: Vec<&str> = content.split_whitespace().collect();
+            if !size_read {
+                if tokens.len() != 3 {
+                    return Err(parse_error(line_number, String::from("expected 'rows columns entries'")));
+                }
+
+                let rows: usize = parse_number(tokens[0], line_number)?;
+                let columns: usize = parse_number(tokens[1], line_number)?;
+                if rows != columns {
+                    return Err(parse_error(line_number, format!("expected a square adjacency matrix, found {}x{}", rows, columns)));
+                }
+                if rows != N {
+                    return Err(GraphFormatError::SizeMismatch { expected: N, found: rows });
+                }
+                entries = parse_number(tokens[2], line_number)?;
+
+                size_read = true;
+                last_line = line_number;
+                continue;
+            }
+            found += 1;
+            last_line = line_number;
+
+            let expected: usize = if pattern { 2 } else { 3 };
+            if tokens.len() != expected {
+                return Err(parse_error(line_number, format!("expected {} fields, found {}", expected, tokens.len())));
+            }
+
+            let i: usize = parse_number(tokens[0], line_number)?;
+            let j: usize = parse_number(tokens[1], line_number)?;
+            if i == 0 || j == 0 {
+                return Err(parse_error(line_number, String::from("indices start at 1")));
+            }
+            let value: u8 = if pattern { 1 } else { parse_number(tokens[2], line_number)? };
+
+            add_edges(&mut result, i - 1, j - 1, value, line_number)?;
+            if symmetric && i != j {
+                add_edges(&mut result, j - 1, i - 1, value, line_number)?;
+            }
+        }
+
+        if !size_read {
+            return Err(parse_error(text.lines().count(), String::from("missing size line")));
+        }
+        if found != entries {
+            return Err(parse_error(last_line, format!("expected {} entries, found {}", entries, found)));
+        }
+
+        Ok(result)
+    }
+
+    pub fn to_graphml(&self) -> String {
+        let undirected: bool = self.is_undirected();
+        let mut result: String = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        result += "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n";
+        result += &format!("  <graph id=\"G\" edgedefault=\"{}\">\n", if undirected { "undirected" } else { "directed" });
+
+        for i in 0..N {
+            result += &format!("    <node id=\"n{}\"/>\n", i);
+        }
+
+        for i in 0..N {
+            let start: usize = if undirected { i } else { 0 };
+            for j in start..N {
+                for _ in 0..self.0[i][j] {
+                    result += &format!("    <edge source=\"n{}\" target=\"n{}\"/>\n", i, j);
+                }
+            }
+        }
+
+        result += "  </graph>\n";
+        result += "</graphml>\n";
+        result
+    }
+
+    pub fn from_graphml(text: &str) -> Result<Graph<N>, GraphFormatError> {
+        // only reads the structure, nodes are numbered in the order they appear
+        let mut undirected: bool = false;
+        let mut nodes: HashMap<String, usize> = HashMap::new();
+        let mut edges: Vec<(String, String, usize)> = Vec::new();
+
+        for (tag, line) in tags(text)? {
+            let name: &str = tag.split_whitespace().next().unwrap_or("");
+            match name {
+                "graph" => {
+                    undirected = attribute(&tag, "edgedefault").as_deref() == Some("undirected");
+                }
+                "node" => {
+                    let id: String = attribute(&tag, "id").ok_or_else(|| parse_error(line, String::from("node without an id")))?;
+                    if nodes.contains_key(&id) {
+                        return Err(parse_error(line, format!("duplicate node id '{}'", id)));
+                    }
+                    let index: usize = nodes.len();
+                    nodes.insert(id, index);
+                }
+                "edge" => {
+                    let source: String = attribute(&tag, "source").ok_or_else(|| parse_error(line, String::from("edge without a source")))?;
+                    let target: String = attribute(&tag, "target").ok_or_else(|| parse_error(line, String::from("edge without a target")))?;
+                    edges.push((source, target, line));
+                }
+                _ => {}
+            }
+        }
+
+        if nodes.len() != N {
+            return Err(GraphFormatError::SizeMismatch { expected: N, found: nodes.len() });
+        }
+
+        let mut result: Graph<N> = Graph::empty();
+        for (source, target, line) in edges {
+            let from: usize = *nodes.get(&source).ok_or_else(|| parse_error(line, format!("unknown node '{}'", source)))?;
+            let to: usize = *nodes.get(&target).ok_or_else(|| parse_error(line, format!("unknown node '{}'", target)))?;
+            add_edges(&mut result, from, to, 1, line)?;
+            if undirected && from != to {
+                add_edges(&mut result, to, from, 1, line)?;
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+fn tags(text: &str) -> Result<Vec<(String, usize)>, GraphFormatError> {
+    // the inside of every <...> tag with the line it starts on, skipping <? ?>, <! > and closing tags
+    let mut result: Vec<(String, usize)> = Vec::new();
+    let mut line: usize = 1;
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\n' {
+            line += 1;
+        }
+        if c != '<' {
+            continue;
+        }
+
+        let start: usize = line;
+        let mut tag: String = String::new();
+        let mut closed: bool = false;
+        for c in chars.by_ref() {
+            if c == '\n' {
+                line += 1;
+            }
+            if c == '>' {
+                closed = true;
+                break;
+            }
+            tag.push(c);
+        }
+
+        if !closed {
+            return Err(parse_error(start, String::from("unterminated tag")));
+        }
+        if tag.starts_with('?') || tag.starts_with('!') || tag.starts_with('/') {
+            continue;
+        }
+
+        result.push((tag.trim_end_matches('/').to_string(), start));
+    }
+
+    Ok(result)
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    // value of name="..." or name='...'
+    let mut rest: &str = tag;
+    while let Some(position) = rest.find(name) {
+        let before: Option<char> = rest[..position].chars().last();
+        let after: &str = rest[(position + name.len())..].trim_start();
+        rest = &rest[(position + name.len())..];
+
+        if before.is_some_and(|c| !c.is_whitespace()) {
+            continue;
+        }
+
+        if let Some(value) = after.strip_prefix('=') {
+            let value: &str = value.trim_start();
+            let quote: char = value.chars().next()?;
+            if quote != '"' && quote != '\'' {
+                return None;
+            }
+
+            let end: usize = value[1..].find(quote)?;
+            return Some(value[1..(end + 1)].to_string());
+        }
+    }
+
+    None
+}
@@ -0,0 +1,127 @@
+use crate::graph::Graph;
+
+// Every generator builds a simple undirected graph, with each edge stored in both directions.
+impl<const N: usize> Graph<N> {
+    pub fn complete() -> Graph<N> {
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            for j in (i + 1)..N {
+                result.add_undirected_edge(i, j);
+            }
+        }
+
+        result
+    }
+
+    pub fn path() -> Graph<N> {
+        // 0 - 1 - ... - (N - 1)
+        let mut result: Graph<N> = Graph::empty();
+        for i in 1..N {
+            result.add_undirected_edge(i - 1, i);
+        }
+
+        result
+    }
+
+    pub fn cycle() -> Graph<N> {
+        assert!(N >= 3, "a cycle needs at least three vertices");
+
+        let mut result: Graph<N> = Graph::path();
+        result.add_undirected_edge(N - 1, 0);
+
+        result
+    }
+
+    pub fn star() -> Graph<N> {
+        // vertex 0 is the centre
+        let mut result: Graph<N> = Graph::empty();
+        for i in 1..N {
+            result.add_undirected_edge(0, i);
+        }
+
+        result
+    }
+
+    pub fn grid(rows: usize, columns: usize) -> Graph<N> {
+        // vertex (r, c) is numbered r * columns + c
+        assert_eq!(rows * columns, N, "the grid needs exactly N vertices");
+
+        let mut result: Graph<N> = Graph::empty();
+        for r in 0..rows {
+            for c in 0..columns {
+                let vertex: usize = (r * columns) + c;
+                if c + 1 < columns {
+                    result.add_undirected_edge(vertex, vertex + 1);
+                }
+                if r + 1 < rows {
+                    result.add_undirected_edge(vertex, vertex + columns);
+                }
+            }
+        }
+
+        result
+    }
+
+    pub fn hypercube() -> Graph<N> {
+        // vertices are bit strings, adjacent when they differ in one bit
+        assert!(N.is_power_of_two(), "a hypercube has a power of two vertices");
+
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            let mut bit: usize = 1;
+            while bit < N {
+                if i & bit == 0 {
+                    result.add_undirected_edge(i, i | bit);
+                }
+                bit <<= 1;
+            }
+        }
+
+        result
+    }
+
+    pub fn erdos_renyi(p: f64, seed: u64) -> Graph<N> {
+        // G(N, p), the same seed always gives the same graph
+        let mut random: SplitMix64 = SplitMix64(seed);
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            for j in (i + 1)..N {
+                if random.next_f64() < p {
+                    result.add_undirected_edge(i, j);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl Graph<10> {
+    pub fn petersen() -> Graph<10> {
+        // outer 5-cycle 0..5, inner pentagram 5..10, spokes i - (i + 5)
+        let mut result: Graph<10> = Graph::empty();
+        for i in 0..5 {
+            result.add_undirected_edge(i, (i + 1) % 5);
+            result.add_undirected_edge(5 + i, 5 + ((i + 2) % 5));
+            result.add_undirected_edge(i, i + 5);
+        }
+
+        result
+    }
+}
+
+struct SplitMix64(u64); // small seeded generator so random graphs don't need a dependency
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z: u64 = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        // top 53 bits as a fraction in [0, 1)
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,301 @@
+use crate::graph::Graph;
+use crate::matrix::{Matrix, symmetric_eigen};
+use crate::vector::Vector;
+
+// Colour refinement (1-dimensional Weisfeiler-Leman) splits the vertices into classes
+// that any isomorphism has to respect, then individualising one vertex at a time and
+// refining again drives the search down to discrete colourings.
+
+type Colouring<const N: usize> = [usize; N];
+type Signature = (usize, Vec<(usize, u8, u8)>); // own colour, then (colour, edges out, edges in) for each neighbour
+
+fn refine<const N: usize>(graphs: &[&[[u8; N]; N]], colourings: &mut [Colouring<N>]) {
+    // refines every colouring against its own graph, ranking the signatures jointly
+    // so that colour numbers mean the same thing in every graph
+    loop {
+        let before: usize = distinct(colourings);
+
+        let mut signatures: Vec<Vec<Signature>> = Vec::new();
+        for (graph, colouring) in graphs.iter().zip(colourings.iter()) {
+            let mut per_vertex: Vec<Signature> = Vec::new();
+            for v in 0..N {
+                let mut neighbourhood: Vec<(usize, u8, u8)> = Vec::new();
+                for u in 0..N {
+                    if graph[v][u] != 0 || graph[u][v] != 0 {
+                        neighbourhood.push((colouring[u], graph[v][u], graph[u][v]));
+                    }
+                }
+                neighbourhood.sort();
+                per_vertex.push((colouring[v], neighbourhood));
+            }
+            signatures.push(per_vertex);
+        }
+
+        let mut ranked: Vec<&Signature> = signatures.iter().flatten().collect();
+        ranked.sort();
+        ranked.dedup();
+
+        for (colouring, per_vertex) in colourings.iter_mut().zip(signatures.iter()) {
+            for v in 0..N {
+                colouring[v] = ranked.binary_search(&&per_vertex[v]).unwrap();
+            }
+        }
+
+        if distinct(colourings) == before {
+            return;
+        }
+    }
+}
+
+fn distinct<const N: usize>(colourings: &[Colouring<N>]) -> usize {
+    let mut colours: Vec<usize> = colourings.iter().flatten().copied().collect();
+    colours.sort();
+    colours.dedup();
+    colours.len()
+}
+
+fn histogram<const N: usize>(colouring: &Colouring<N>) -> Colouring<N> {
+    let mut result: Colouring<N> = *colouring;
+    result.sort();
+    result
+}
+
+fn individualize<const N: usize>(colouring: &Colouring<N>, vertex: usize) -> Colouring<N> {
+    // splits vertex off the front of its class, refine renumbers the colours afterwards
+    let mut result: Colouring<N> = [0; N];
+    for u in 0..N {
+        result[u] = (2 * colouring[u]) + if u == vertex { 0 } else { 1 };
+    }
+
+    result
+}
+
+fn target_cell<const N: usize>(colouring: &Colouring<N>) -> Option<Vec<usize>> {
+    // the smallest class with more than one vertex, lowest colour first, None once the colouring is discrete
+    let mut best: Option<Vec<usize>> = None;
+    for colour in histogram(colouring) {
+        let cell: Vec<usize> = (0..N).filter(|&v| colouring[v] == colour).collect();
+        if cell.len() > 1 && best.as_ref().is_none_or(|b| cell.len() < b.len()) {
+            best = Some(cell);
+        }
+    }
+
+    best
+}
+
+fn orbit<const N: usize>(generators: &[&[usize; N]], start: usize) -> Vec<bool> {
+    let mut seen: Vec<bool> = vec![false; N];
+    seen[start] = true;
+    let mut stack: Vec<usize> = vec![start];
+    while let Some(v) = stack.pop() {
+        for generator in generators {
+            if !seen[generator[v]] {
+                seen[generator[v]] = true;
+                stack.push(generator[v]);
+            }
+        }
+    }
+
+    seen
+}
+
+fn fixes<const N: usize>(permutation: &[usize; N], points: &[usize]) -> bool {
+    points.iter().all(|&p| permutation[p] == p)
+}
+
+fn search_isomorphism<const N: usize>(a: &[[u8; N]; N], b: &[[u8; N]; N], colours_a: Colouring<N>, colours_b: Colouring<N>) -> Option<[usize; N]> {
+    // a mapping m with a[i][j] == b[m[i]][m[j]] that carries colours_a onto colours_b
+    let mut colourings: [Colouring<N>; 2] = [colours_a, colours_b];
+    refine(&[a, b], &mut colourings);
+    let [colours_a, colours_b] = colourings;
+
+    if histogram(&colours_a) != histogram(&colours_b) {
+        return None;
+    }
+
+    let cell: Vec<usize> = match target_cell(&colours_a) {
+        Some(cell) => cell,
+        None => {
+            // discrete, so the colours pin down the only candidate
+            let mut mapping: [usize; N] = [0; N];
+            for v in 0..N {
+                mapping[v] = (0..N).find(|&w| colours_b[w] == colours_a[v]).unwrap();
+            }
+
+            for i in 0..N {
+                for j in 0..N {
+                    if a[i][j] != b[mapping[i]][mapping[j]] {
+                        return None;
+                    }
+                }
+            }
+
+            return Some(mapping);
+        }
+    };
+
+    let v: usize = cell[0];
+    for w in 0..N {
+        if colours_b[w] != colours_a[v] {
+            continue;
+        }
+
+        let found: Option<[usize; N]> = search_isomorphism(a, b, individualize(&colours_a, v), individualize(&colours_b, w));
+        if found.is_some() {
+            return found;
+        }
+    }
+
+    None
+}
+
+impl<const N: usize> Graph<N> {
+    pub fn degree_sequence(&self) -> Vec<(usize, usize)> {
+        // (out degree, in degree) pairs in decreasing order, parallel edges counted
+        let mut result: Vec<(usize, usize)> = Vec::new();
+        for v in 0..N {
+            let out_degree: usize = (0..N).map(|u| self.0[v][u] as usize).sum();
+            let in_degree: usize = (0..N).map(|u| self.0[u][v] as usize).sum();
+            result.push((out_degree, in_degree));
+        }
+        result.sort();
+        result.reverse();
+
+        result
+    }
+
+    pub fn adjacency_spectrum(&self) -> Vector<f64, N> {
+        // eigenvalues of the adjacency matrix in ascending order, only real for undirected graphs
+        assert!(self.is_undirected(), "the adjacency spectrum needs an undirected graph");
+
+        let mut adjacency: [[f64; N]; N] = [[0.0; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                adjacency[i][j] = f64::from(self.0[i][j]);
+            }
+        }
+
+        symmetric_eigen(Matrix::from(adjacency)).0
+    }
+
+    pub fn isomorphism(&self, other: &Graph<N>) -> Option<Matrix<u8, N, N>> {
+        // returns a permutation matrix P with P A P^T = B, where A is self and B is other
+        if self.degree_sequence() != other.degree_sequence() {
+            return None;
+        }
+
+        if self.is_undirected() && other.is_undirected() {
+            let (first, second) = (self.adjacency_spectrum(), other.adjacency_spectrum());
+            for i in 0..N {
+                if (first[i] - second[i]).abs() > 1e-8 {
+                    return None;
+                }
+            }
+        }
+
+        let mapping: [usize; N] = search_isomorphism(&self.0, &other.0, [0; N], [0; N])?;
+        let mut permutation: [[u8; N]; N] = [[0; N]; N];
+        for i in 0..N {
+            permutation[mapping[i]][i] = 1;
+        }
+
+        Some(Matrix::from(permutation))
+    }
+
+    pub fn is_isomorphic(&self, other: &Graph<N>) -> bool {
+        self.isomorphism(other).is_some()
+    }
+
+    pub fn automorphism_generators(&self) -> Vec<[usize; N]> {
+        // walks down a base b_1, b_2, ... and, for every point its stabilizer can still move b_i to,
+        // finds one automorphism doing it, the result generates the whole automorphism group
+        let mut colouring: [Colouring<N>; 1] = [[0; N]];
+        refine(&[&self.0], &mut colouring);
+
+        let mut generators: Vec<[usize; N]> = Vec::new();
+        let mut base: Vec<usize> = Vec::new();
+        while let Some(cell) = target_cell(&colouring[0]) {
+            let b: usize = cell[0];
+            for &w in &cell[1..] {
+                let stabilizer: Vec<&[usize; N]> = generators.iter().filter(|g| fixes(g, &base)).collect();
+                if orbit(&stabilizer, b)[w] {
+                    continue;
+                }
+
+                let found: Option<[usize; N]> = search_isomorphism(&self.0, &self.0, individualize(&colouring[0], b), individualize(&colouring[0], w));
+                if let Some(automorphism) = found {
+                    generators.push(automorphism);
+                }
+            }
+
+            base.push(b);
+            colouring[0] = individualize(&colouring[0], b);
+            refine(&[&self.0], &mut colouring);
+        }
+
+        generators
+    }
+
+    pub fn canonical_labeling(&self) -> [usize; N] {
+        // the relabeling v -> labeling[v] whose adjacency array is smallest among the leaves of the search,
+        // isomorphic graphs end up with identical canonical forms
+        let generators: Vec<[usize; N]> = self.automorphism_generators();
+        let mut best: Option<([[u8; N]; N], [usize; N])> = None;
+        self.canonical_search([0; N], &mut Vec::new(), &generators, &mut best);
+
+        best.unwrap().1
+    }
+
+    fn canonical_search(&self, colouring: Colouring<N>, prefix: &mut Vec<usize>, generators: &[[usize; N]], best: &mut Option<([[u8; N]; N], [usize; N])>) {
+        let mut colouring: [Colouring<N>; 1] = [colouring];
+        refine(&[&self.0], &mut colouring);
+        let colouring: Colouring<N> = colouring[0];
+
+        let cell: Vec<usize> = match target_cell(&colouring) {
+            Some(cell) => cell,
+            None => {
+                // a discrete colouring numbers the vertices 0..N
+                let mut form: [[u8; N]; N] = [[0; N]; N];
+                for i in 0..N {
+                    for j in 0..N {
+                        form[colouring[i]][colouring[j]] = self.0[i][j];
+                    }
+                }
+
+                if best.as_ref().is_none_or(|(best_form, _)| form < *best_form) {
+                    *best = Some((form, colouring));
+                }
+                return;
+            }
+        };
+
+        // children in the same orbit of the prefix stabilizer lead to the same forms
+        let stabilizer: Vec<&[usize; N]> = generators.iter().filter(|g| fixes(g, prefix)).collect();
+        let mut tried: Vec<bool> = vec![false; N];
+        for w in cell {
+            if tried[w] {
+                continue;
+            }
+            for (v, equivalent) in orbit(&stabilizer, w).into_iter().enumerate() {
+                tried[v] = tried[v] || equivalent;
+            }
+
+            prefix.push(w);
+            self.canonical_search(individualize(&colouring, w), prefix, generators, best);
+            prefix.pop();
+        }
+    }
+
+    pub fn canonical_form(&self) -> Graph<N> {
+        // suitable for hashing, equal exactly when the graphs are isomorphic
+        let labeling: [usize; N] = self.canonical_labeling();
+        let mut result: Graph<N> = Graph::empty();
+        for i in 0..N {
+            for j in 0..N {
+                result.0[labeling[i]][labeling[j]] = self.0[i][j];
+            }
+        }
+
+        result
+    }
+}
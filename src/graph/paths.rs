@@ -0,0 +1,259 @@
+use num::Num;
+use std::fmt;
+use std::error::Error;
+
+use crate::graph::{Graph, WeightedGraph};
+use crate::matrix::Matrix;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NegativeCycle(pub Vec<usize>); // the vertices of the cycle, in order
+impl fmt::Display for NegativeCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "negative cycle through vertices {:?}", self.0)
+    }
+}
+impl Error for NegativeCycle {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ShortestPaths<W: Num + Copy, const N: usize> {
+    source: usize,
+    distance: [Option<W>; N],
+    predecessor: [Option<usize>; N]
+}
+impl<W: Num + Copy, const N: usize> ShortestPaths<W, N> {
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    pub fn distances(&self) -> [Option<W>; N] {
+        self.distance
+    }
+
+    pub fn distance(&self, to: usize) -> Option<W> {
+        self.distance[to]
+    }
+
+    pub fn path_to(&self, to: usize) -> Option<Vec<usize>> {
+        // walks the predecessors back to the source
+        self.distance[to]?;
+
+        let mut path: Vec<usize> = vec![to];
+        let mut current: usize = to;
+        while current != self.source {
+            current = self.predecessor[current]?;
+            path.push(current);
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AllPairsShortestPaths<W: Num + Copy, const N: usize> {
+    distance: [[Option<W>; N]; N],
+    next: [[Option<usize>; N]; N]
+}
+impl<W: Num + Copy, const N: usize> AllPairsShortestPaths<W, N> {
+    pub fn distance(&self, from: usize, to: usize) -> Option<W> {
+        self.distance[from][to]
+    }
+
+    pub fn distance_matrix(&self, unreachable: W) -> Matrix<W, N, N> {
+        // Matrix has no room for "no path", so the caller picks a stand in (infinity, max value, ...)
+        let mut result: [[W; N]; N] = [[unreachable; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                if let Some(distance) = self.distance[i][j] {
+                    result[i][j] = distance;
+                }
+            }
+        }
+
+        Matrix::from(result)
+    }
+
+    pub fn path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.distance[from][to]?;
+
+        let mut path: Vec<usize> = vec![from];
+        let mut current: usize = from;
+        while current != to {
+            current = self.next[current][to]?;
+            path.push(current);
+        }
+
+        Some(path)
+    }
+}
+
+impl<const N: usize> Graph<N> {
+    pub fn bfs_distances(&self, source: usize) -> ShortestPaths<usize, N> {
+        // unweighted, every non-zero entry is an edge of length 1
+        let mut distance: [Option<usize>; N] = [None; N];
+        let mut predecessor: [Option<usize>; N] = [None; N];
+        let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::new();
+
+        distance[source] = Some(0);
+        queue.push_back(source);
+        while let Some(current) = queue.pop_front() {
+            let next_distance: usize = distance[current].unwrap() + 1;
+            for next in 0..N {
+                if self.0[current][next] != 0 && distance[next].is_none() {
+                    distance[next] = Some(next_distance);
+                    predecessor[next] = Some(current);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        ShortestPaths { source, distance, predecessor }
+    }
+
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        self.bfs_distances(from).path_to(to)
+    }
+}
+
+impl<W: Num + Copy + PartialOrd, const N: usize> WeightedGraph<W, N> {
+    pub fn dijkstra(&self, source: usize) -> ShortestPaths<W, N> {
+        // the O(N^2) array version, which suits a dense adjacency array better than a heap
+        let mut distance: [Option<W>; N] = [None; N];
+        let mut predecessor: [Option<usize>; N] = [None; N];
+        let mut done: [bool; N] = [false; N];
+
+        distance[source] = Some(W::zero());
+        loop {
+            // closest vertex that is not done yet
+            let mut closest: Option<usize> = None;
+            for i in 0..N {
+                if let (false, Some(d)) = (done[i], distance[i]) {
+                    if closest.is_none() || d < distance[closest.unwrap()].unwrap() {
+                        closest = Some(i);
+                    }
+                }
+            }
+
+            let current: usize = match closest {
+                Some(current) => current,
+                None => break
+            };
+            done[current] = true;
+
+            for next in 0..N {
+                if let Some(weight) = self.0[current][next] {
+                    assert!(weight >= W::zero(), "dijkstra needs non-negative weights, use bellman_ford");
+
+                    let candidate: W = distance[current].unwrap() + weight;
+                    if distance[next].is_none() || candidate < distance[next].unwrap() {
+                        distance[next] = Some(candidate);
+                        predecessor[next] = Some(current);
+                    }
+                }
+            }
+        }
+
+        ShortestPaths { source, distance, predecessor }
+    }
+
+    pub fn bellman_ford(&self, source: usize) -> Result<ShortestPaths<W, N>, NegativeCycle> {
+        let mut distance: [Option<W>; N] = [None; N];
+        let mut predecessor: [Option<usize>; N] = [None; N];
+        distance[source] = Some(W::zero());
+
+        // relaxes every edge once, returns a vertex whose distance improved
+        let relax = |distance: &mut [Option<W>; N], predecessor: &mut [Option<usize>; N]| -> Option<usize> {
+            let mut changed: Option<usize> = None;
+            for i in 0..N {
+                let start: W = match distance[i] {
+                    Some(start) => start,
+                    None => continue
+                };
+
+                for j in 0..N {
+                    if let Some(weight) = self.0[i][j] {
+                        let candidate: W = start + weight;
+                        if distance[j].is_none() || candidate < distance[j].unwrap() {
+                            distance[j] = Some(candidate);
+                            predecessor[j] = Some(i);
+                            changed = Some(j);
+                        }
+                    }
+                }
+            }
+
+            changed
+        };
+
+        for _ in 1..N {
+            if relax(&mut distance, &mut predecessor).is_none() {
+                break;
+            }
+        }
+
+        if let Some(changed) = relax(&mut distance, &mut predecessor) {
+            // stepping back N times from a vertex that still improves is guaranteed to land on the cycle
+            let mut current: usize = changed;
+            for _ in 0..N {
+                current = predecessor[current].unwrap();
+            }
+
+            let mut cycle: Vec<usize> = vec![current];
+            let mut previous: usize = predecessor[current].unwrap();
+            while previous != current {
+                cycle.push(previous);
+                previous = predecessor[previous].unwrap();
+            }
+            cycle.reverse();
+
+            return Err(NegativeCycle(cycle));
+        }
+
+        Ok(ShortestPaths { source, distance, predecessor })
+    }
+
+    pub fn floyd_warshall(&self) -> Result<AllPairsShortestPaths<W, N>, NegativeCycle> {
+        let mut distance: [[Option<W>; N]; N] = self.0;
+        let mut next: [[Option<usize>; N]; N] = [[None; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                if distance[i][j].is_some() {
+                    next[i][j] = Some(j);
+                }
+            }
+
+            if distance[i][i].is_none() || distance[i][i].unwrap() > W::zero() {
+                distance[i][i] = Some(W::zero());
+                next[i][i] = Some(i);
+            }
+        }
+
+        for k in 0..N {
+            for i in 0..N {
+                let first: W = match distance[i][k] {
+                    Some(first) => first,
+                    None => continue
+                };
+
+                for j in 0..N {
+                    if let Some(second) = distance[k][j] {
+                        let candidate: W = first + second;
+                        if distance[i][j].is_none() || candidate < distance[i][j].unwrap() {
+                            distance[i][j] = Some(candidate);
+                            next[i][j] = next[i][k];
+                        }
+                    }
+                }
+            }
+        }
+
+        for i in 0..N {
+            if distance[i][i].unwrap() < W::zero() {
+                // i sits on a negative cycle, let bellman_ford dig it out
+                self.bellman_ford(i)?;
+            }
+        }
+
+        Ok(AllPairsShortestPaths { distance, next })
+    }
+}
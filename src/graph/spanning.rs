@@ -0,0 +1,110 @@
+use num::Num;
+
+use crate::graph::WeightedGraph;
+use crate::matrix::nan_last;
+
+// Both read the graph as undirected, an edge stored in both directions keeps the lighter weight,
+// and both return a minimum spanning forest when the graph is disconnected.
+impl<W: Num + Copy + PartialOrd, const N: usize> WeightedGraph<W, N> {
+    fn undirected_weight(&self, i: usize, j: usize) -> Option<W> {
+        match (self.0[i][j], self.0[j][i]) {
+            (Some(a), Some(b)) => Some(if nan_last(b, a).is_lt() { b } else { a }),
+            (a, b) => a.or(b)
+        }
+    }
+
+    pub fn kruskal(&self) -> WeightedGraph<W, N> {
+        let mut edges: Vec<(W, usize, usize)> = Vec::new();
+        for i in 0..N {
+            for j in (i + 1)..N {
+                if let Some(weight) = self.undirected_weight(i, j) {
+                    edges.push((weight, i, j));
+                }
+            }
+        }
+        edges.sort_by(|a, b| nan_last(a.0, b.0));
+
+        // union find with path halving
+        let mut parent: [usize; N] = [0; N];
+        for i in 0..N {
+            parent[i] = i;
+        }
+        let find = |parent: &mut [usize; N], mut v: usize| -> usize {
+            while parent[v] != v {
+                parent[v] = parent[parent[v]];
+                v = parent[v];
+            }
+            v
+        };
+
+        let mut result: [[Option<W>; N]; N] = [[None; N]; N];
+        for (weight, i, j) in edges {
+            let (root_i, root_j) = (find(&mut parent, i), find(&mut parent, j));
+            if root_i != root_j {
+                parent[root_i] = root_j;
+                result[i][j] = Some(weight);
+                result[j][i] = Some(weight);
+            }
+        }
+
+        WeightedGraph(result)
+    }
+
+    pub fn prim(&self) -> WeightedGraph<W, N> {
+        // the O(N^2) array version, restarted from every vertex not yet in the forest
+        let mut in_tree: [bool; N] = [false; N];
+        let mut best: [Option<(W, usize)>; N] = [None; N]; // lightest edge into the tree and where it comes from
+        let mut result: [[Option<W>; N]; N] = [[None; N]; N];
+
+        for root in 0..N {
+            if in_tree[root] {
+                continue;
+            }
+
+            let mut next: Option<usize> = Some(root);
+            while let Some(current) = next {
+                in_tree[current] = true;
+                if let Some((weight, from)) = best[current] {
+                    result[current][from] = Some(weight);
+                    result[from][current] = Some(weight);
+                }
+
+                for v in 0..N {
+                    if in_tree[v] {
+                        continue;
+                    }
+                    if let Some(weight) = self.undirected_weight(current, v) {
+                        if best[v].is_none() || nan_last(weight, best[v].unwrap().0).is_lt() {
+                            best[v] = Some((weight, current));
+                        }
+                    }
+                }
+
+                next = None;
+                for v in 0..N {
+                    if let (false, Some((weight, _))) = (in_tree[v], best[v]) {
+                        if next.is_none() || nan_last(weight, best[next.unwrap()].unwrap().0).is_lt() {
+                            next = Some(v);
+                        }
+                    }
+                }
+            }
+        }
+
+        WeightedGraph(result)
+    }
+
+    pub fn total_weight(&self) -> W {
+        // every stored entry once, so an undirected edge counts twice
+        let mut total: W = W::zero();
+        for i in 0..N {
+            for j in 0..N {
+                if let Some(weight) = self.0[i][j] {
+                    total = total + weight;
+                }
+            }
+        }
+
+        total
+    }
+}
@@ -0,0 +1,164 @@
+use num::{Float, Num, NumCast};
+
+use crate::graph::Graph;
+use crate::matrix::{Matrix, symmetric_eigen};
+use crate::vector::Vector;
+
+// The spectral methods read the graph as undirected, so the adjacency array is expected to be symmetric.
+impl<const N: usize> Graph<N> {
+    pub fn degrees(&self) -> [usize; N] {
+        // row sums, counting parallel edges
+        let mut result: [usize; N] = [0; N];
+        for i in 0..N {
+            for j in 0..N {
+                result[i] += self.0[i][j] as usize;
+            }
+        }
+
+        result
+    }
+
+    pub fn degree_matrix<F: Num + Copy + NumCast>(&self) -> Matrix<F, N, N> {
+        let degrees: [usize; N] = self.degrees();
+        let mut result: [[F; N]; N] = [[F::zero(); N]; N];
+        for i in 0..N {
+            result[i][i] = NumCast::from(degrees[i]).unwrap();
+        }
+
+        Matrix::from(result)
+    }
+
+    pub fn laplacian<F: Num + Copy + NumCast>(&self) -> Matrix<F, N, N> {
+        // L = D - A
+        let mut result: Matrix<F, N, N> = self.degree_matrix();
+        for i in 0..N {
+            for j in 0..N {
+                let entry: F = NumCast::from(self.0[i][j]).unwrap();
+                result[i][j] = result[i][j] - entry;
+            }
+        }
+
+        result
+    }
+
+    pub fn normalized_laplacian<F: Float>(&self) -> Matrix<F, N, N> {
+        // I - D^{-1/2} A D^{-1/2}, isolated vertices get a zero row
+        let degrees: [usize; N] = self.degrees();
+        let mut result: [[F; N]; N] = [[F::zero(); N]; N];
+        for i in 0..N {
+            if degrees[i] == 0 {
+                continue;
+            }
+
+            result[i][i] = F::one();
+            for j in 0..N {
+                if self.0[i][j] != 0 && degrees[j] != 0 {
+                    let entry: F = NumCast::from(self.0[i][j]).unwrap();
+                    let scale: F = NumCast::from(degrees[i] * degrees[j]).unwrap();
+                    result[i][j] = result[i][j] - (entry / scale.sqrt());
+                }
+            }
+        }
+
+        Matrix::from(result)
+    }
+
+    pub fn fiedler_vector<F: Float>(&self) -> Vector<F, N> {
+        // eigenvector of the second smallest Laplacian eigenvalue (the algebraic connectivity)
+        assert!(N >= 2, "the Fiedler vector needs at least two vertices");
+
+        let (_, vectors) = symmetric_eigen(self.laplacian::<F>());
+        vectors.get_column(1)
+    }
+
+    pub fn spectral_bisection(&self) -> [bool; N] {
+        // splits the vertices by the sign of their Fiedler vector entry
+        let fiedler: Vector<f64, N> = self.fiedler_vector();
+        let mut result: [bool; N] = [false; N];
+        for i in 0..N {
+            result[i] = fiedler[i] >= 0.0;
+        }
+
+        result
+    }
+
+    pub fn spectral_clustering(&self, k: usize) -> [usize; N] {
+        // embeds every vertex with the k smallest Laplacian eigenvectors and runs k-means on the rows,
+        // returns the cluster of each vertex
+        assert!(k >= 1 && k <= N, "need between 1 and N clusters");
+
+        let (_, vectors) = symmetric_eigen(self.laplacian::<f64>());
+        let points: Vec<Vec<f64>> = (0..N).map(|i| vectors[i][..k].to_vec()).collect();
+        let distance = |a: &[f64], b: &[f64]| -> f64 {
+            a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum()
+        };
+
+        // farthest point initialisation keeps the result deterministic
+        let mut centres: Vec<Vec<f64>> = vec![points[0].clone()];
+        while centres.len() < k {
+            let mut farthest: usize = 0;
+            let mut farthest_distance: f64 = -1.0;
+            for i in 0..N {
+                let nearest: f64 = centres.iter().map(|c| distance(&points[i], c)).fold(f64::INFINITY, f64::min);
+                if nearest > farthest_distance {
+                    farthest = i;
+                    farthest_distance = nearest;
+                }
+            }
+            centres.push(points[farthest].clone());
+        }
+
+        let mut labels: [usize; N] = [0; N];
+        for _iteration in 0..100 {
+            let mut changed: bool = false;
+            for i in 0..N {
+                let mut best: usize = 0;
+                for c in 1..k {
+                    if distance(&points[i], &centres[c]) < distance(&points[i], &centres[best]) {
+                        best = c;
+                    }
+                }
+
+                if best != labels[i] {
+                    labels[i] = best;
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+
+            for c in 0..k {
+                let members: Vec<usize> = (0..N).filter(|&i| labels[i] == c).collect();
+                if members.is_empty() {
+                    continue;
+                }
+
+                for d in 0..k {
+                    centres[c][d] = members.iter().map(|&i| points[i][d]).sum::<f64>() / members.len() as f64;
+                }
+            }
+        }
+
+        labels
+    }
+
+    pub fn spanning_tree_count(&self) -> u128 {
+        // Kirchhoff's matrix tree theorem, any cofactor of the Laplacian.
+        // Matrix<_, N - 1, N - 1> needs generic_const_exprs, so instead of deleting
+        // row and column 0 they are replaced with the identity, which leaves the same determinant.
+        if N == 0 {
+            return 0;
+        }
+
+        let mut minor: Matrix<i128, N, N> = self.laplacian();
+        for i in 0..N {
+            minor[0][i] = 0;
+            minor[i][0] = 0;
+        }
+        minor[0][0] = 1;
+
+        minor.determinant() as u128
+    }
+}
@@ -0,0 +1,368 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::VecDeque;
+
+use crate::graph::Graph;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cycle(pub Vec<usize>); // the vertices of the cycle, in order
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cycle through vertices {:?}", self.0)
+    }
+}
+impl Error for Cycle {}
+
+pub struct Dfs<'a, const N: usize> {
+    graph: &'a Graph<N>,
+    stack: Vec<usize>,
+    visited: [bool; N]
+}
+impl<const N: usize> Iterator for Dfs<'_, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        // preorder, lower numbered neighbours first
+        while let Some(current) = self.stack.pop() {
+            if self.visited[current] {
+                continue;
+            }
+            self.visited[current] = true;
+
+            for next in (0..N).rev() {
+                if self.graph.0[current][next] != 0 && !self.visited[next] {
+                    self.stack.push(next);
+                }
+            }
+
+            return Some(current);
+        }
+
+        None
+    }
+}
+
+pub struct Bfs<'a, const N: usize> {
+    graph: &'a Graph<N>,
+    queue: VecDeque<usize>,
+    visited: [bool; N]
+}
+impl<const N: usize> Iterator for Bfs<'_, N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let current: usize = self.queue.pop_front()?;
+        for next in 0..N {
+            if self.graph.0[current][next] != 0 && !self.visited[next] {
+                self.visited[next] = true;
+                self.queue.push_back(next);
+            }
+        }
+
+        Some(current)
+    }
+}
+
+impl<const N: usize> Graph<N> {
+    pub fn dfs(&self, start: usize) -> Dfs<'_, N> {
+        Dfs { graph: self, stack: vec![start], visited: [false; N] }
+    }
+
+    pub fn bfs(&self, start: usize) -> Bfs<'_, N> {
+        let mut visited: [bool; N] = [false; N];
+        visited[start] = true;
+
+        Bfs { graph: self, queue: VecDeque::from([start]), visited }
+    }
+
+    fn multiplicity(&self, i: usize, j: usize) -> u8 {
+        // edge count when the graph is read as undirected
+        self.0[i][j].max(self.0[j][i])
+    }
+
+    pub fn connected_components(&self) -> Vec<Vec<usize>> {
+        // ignores edge direction, so these are the weakly connected components of a digraph
+        let mut component: [Option<usize>; N] = [None; N];
+        let mut components: Vec<Vec<usize>> = Vec::new();
+
+        for start in 0..N {
+            if component[start].is_some() {
+                continue;
+            }
+
+            let mut members: Vec<usize> = Vec::new();
+            let mut stack: Vec<usize> = vec![start];
+            component[start] = Some(components.len());
+            while let Some(current) = stack.pop() {
+                members.push(current);
+                for next in 0..N {
+                    if self.multiplicity(current, next) != 0 && component[next].is_none() {
+                        component[next] = Some(components.len());
+                        stack.push(next);
+                    }
+                }
+            }
+
+            members.sort();
+            components.push(members);
+        }
+
+        components
+    }
+
+    pub fn strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        // Tarjan's algorithm, components come out in reverse topological order
+        let mut state: Tarjan<N> = Tarjan {
+            index: [None; N],
+            low: [0; N],
+            on_stack: [false; N],
+            stack: Vec::new(),
+            counter: 0,
+            components: Vec::new()
+        };
+
+        for v in 0..N {
+            if state.index[v].is_none() {
+                self.strong_connect(v, &mut state);
+            }
+        }
+
+        state.components
+    }
+
+    fn strong_connect(&self, v: usize, state: &mut Tarjan<N>) {
+        state.index[v] = Some(state.counter);
+        state.low[v] = state.counter;
+        state.counter += 1;
+        state.stack.push(v);
+        state.on_stack[v] = true;
+
+        for w in 0..N {
+            if self.0[v][w] == 0 {
+                continue;
+            }
+
+            match state.index[w] {
+                None => {
+                    self.strong_connect(w, state);
+                    state.low[v] = state.low[v].min(state.low[w]);
+                }
+                Some(index) if state.on_stack[w] => {
+                    state.low[v] = state.low[v].min(index);
+                }
+                _ => {}
+            }
+        }
+
+        // v is the root of a component, pop it off the stack
+        if Some(state.low[v]) == state.index[v] {
+            let mut component: Vec<usize> = Vec::new();
+            while let Some(w) = state.stack.pop() {
+                state.on_stack[w] = false;
+                component.push(w);
+                if w == v {
+                    break;
+                }
+            }
+
+            component.sort();
+            state.components.push(component);
+        }
+    }
+
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Cycle> {
+        // depth first, with a grey / black colouring to catch back edges
+        let mut colour: [Colour; N] = [Colour::White; N];
+        let mut parent: [Option<usize>; N] = [None; N];
+        let mut order: Vec<usize> = Vec::new();
+
+        for start in 0..N {
+            if colour[start] != Colour::White {
+                continue;
+            }
+
+            // each stack entry is a vertex and the next neighbour to look at
+            let mut stack: Vec<(usize, usize)> = vec![(start, 0)];
+            colour[start] = Colour::Grey;
+            while let Some(&mut (current, ref mut next)) = stack.last_mut() {
+                if *next == N {
+                    colour[current] = Colour::Black;
+                    order.push(current);
+                    stack.pop();
+                    continue;
+                }
+
+                let w: usize = *next;
+                *next += 1;
+                if self.0[current][w] == 0 {
+                    continue;
+                }
+
+                match colour[w] {
+                    Colour::White => {
+                        colour[w] = Colour::Grey;
+                        parent[w] = Some(current);
+                        stack.push((w, 0));
+                    }
+                    Colour::Grey => {
+                        // back edge current -> w, the cycle is w ~> current -> w
+                        let mut cycle: Vec<usize> = vec![current];
+                        let mut v: usize = current;
+                        while v != w {
+                            v = parent[v].unwrap();
+                            cycle.push(v);
+                        }
+                        cycle.reverse();
+
+                        return Err(Cycle(cycle));
+                    }
+                    Colour::Black => {}
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    pub fn bipartition(&self) -> Option<[bool; N]> {
+        // two colouring by breadth first search, None if there is an odd cycle
+        let mut side: [Option<bool>; N] = [None; N];
+        for start in 0..N {
+            if side[start].is_some() {
+                continue;
+            }
+
+            side[start] = Some(false);
+            let mut queue: VecDeque<usize> = VecDeque::from([start]);
+            while let Some(current) = queue.pop_front() {
+                let current_side: bool = side[current].unwrap();
+                for next in 0..N {
+                    if self.multiplicity(current, next) == 0 {
+                        continue;
+                    }
+
+                    match side[next] {
+                        None => {
+                            side[next] = Some(!current_side);
+                            queue.push_back(next);
+                        }
+                        Some(next_side) if next_side == current_side => return None,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let mut result: [bool; N] = [false; N];
+        for i in 0..N {
+            result[i] = side[i].unwrap();
+        }
+
+        Some(result)
+    }
+
+    pub fn is_bipartite(&self) -> bool {
+        self.bipartition().is_some()
+    }
+
+    pub fn articulation_points(&self) -> Vec<usize> {
+        let mut state: LowLink<N> = LowLink::new();
+        for v in 0..N {
+            if state.discovery[v].is_none() {
+                self.low_link(v, None, &mut state);
+            }
+        }
+
+        let mut points: Vec<usize> = Vec::new();
+        for v in 0..N {
+            if state.articulation[v] {
+                points.push(v);
+            }
+        }
+
+        points
+    }
+
+    pub fn bridges(&self) -> Vec<(usize, usize)> {
+        // undirected edges (u, v) with u < v whose removal disconnects the graph,
+        // parallel edges are never bridges
+        let mut state: LowLink<N> = LowLink::new();
+        for v in 0..N {
+            if state.discovery[v].is_none() {
+                self.low_link(v, None, &mut state);
+            }
+        }
+
+        state.bridges.sort();
+        state.bridges
+    }
+
+    fn low_link(&self, v: usize, parent: Option<usize>, state: &mut LowLink<N>) {
+        let discovery: usize = state.counter;
+        state.discovery[v] = Some(discovery);
+        state.low[v] = discovery;
+        state.counter += 1;
+
+        let mut children: usize = 0;
+        for w in 0..N {
+            if w == v || self.multiplicity(v, w) == 0 {
+                continue;
+            }
+
+            match state.discovery[w] {
+                None => {
+                    children += 1;
+                    self.low_link(w, Some(v), state);
+                    state.low[v] = state.low[v].min(state.low[w]);
+
+                    if parent.is_some() && state.low[w] >= discovery {
+                        state.articulation[v] = true;
+                    }
+                    if state.low[w] > discovery && self.multiplicity(v, w) == 1 {
+                        state.bridges.push((v.min(w), v.max(w)));
+                    }
+                }
+                Some(index) => {
+                    // the tree edge back to the parent only counts if there is a parallel copy of it
+                    if Some(w) != parent || self.multiplicity(v, w) > 1 {
+                        state.low[v] = state.low[v].min(index);
+                    }
+                }
+            }
+        }
+
+        if parent.is_none() && children > 1 {
+            state.articulation[v] = true;
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Colour {
+    White, // unvisited
+    Grey, // on the current path
+    Black // finished
+}
+
+struct Tarjan<const N: usize> {
+    index: [Option<usize>; N],
+    low: [usize; N],
+    on_stack: [bool; N],
+    stack: Vec<usize>,
+    counter: usize,
+    components: Vec<Vec<usize>>
+}
+
+struct LowLink<const N: usize> {
+    discovery: [Option<usize>; N],
+    low: [usize; N],
+    counter: usize,
+    articulation: [bool; N],
+    bridges: Vec<(usize, usize)>
+}
+impl<const N: usize> LowLink<N> {
+    fn new() -> Self {
+        LowLink { discovery: [None; N], low: [0; N], counter: 0, articulation: [false; N], bridges: Vec::new() }
+    }
+}
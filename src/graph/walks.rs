@@ -0,0 +1,81 @@
+use crate::graph::Graph;
+use crate::matrix::Matrix;
+
+impl<const N: usize> Graph<N> {
+    pub fn walk_matrix(&self, k: u32) -> Matrix<u64, N, N> {
+        // entry (i, j) of A^k counts the walks of length k from i to j,
+        // widened to u64 since u8 entries overflow after a couple of steps
+        let mut adjacency: [[u64; N]; N] = [[0; N]; N];
+        for i in 0..N {
+            for j in 0..N {
+                adjacency[i][j] = u64::from(self.0[i][j]);
+            }
+        }
+
+        Matrix::from(adjacency).pow(k)
+    }
+
+    pub fn count_walks(&self, from: usize, to: usize, k: u32) -> u64 {
+        self.walk_matrix(k)[from][to]
+    }
+
+    pub fn count_simple_paths(&self, from: usize, to: usize) -> u64 {
+        // backtracking over paths that never revisit a vertex, of any length,
+        // edge multiplicities are ignored
+        let mut visited: [bool; N] = [false; N];
+        visited[from] = true;
+
+        self.extend_paths(from, to, &mut visited)
+    }
+
+    fn extend_paths(&self, current: usize, to: usize, visited: &mut [bool; N]) -> u64 {
+        if current == to {
+            return 1;
+        }
+
+        let mut count: u64 = 0;
+        for next in 0..N {
+            if self.0[current][next] != 0 && !visited[next] {
+                visited[next] = true;
+                count += self.extend_paths(next, to, visited);
+                visited[next] = false;
+            }
+        }
+
+        count
+    }
+
+    pub fn count_cycles(&self, k: usize) -> u64 {
+        // directed simple cycles with k edges, each found once from its smallest vertex,
+        // an undirected graph stores every edge both ways so each cycle of length >= 3 shows up twice
+        if k == 0 {
+            return 0;
+        }
+
+        let mut count: u64 = 0;
+        for start in 0..N {
+            let mut visited: [bool; N] = [false; N];
+            visited[start] = true;
+            count += self.extend_cycles(start, start, 1, k, &mut visited);
+        }
+
+        count
+    }
+
+    fn extend_cycles(&self, start: usize, current: usize, length: usize, k: usize, visited: &mut [bool; N]) -> u64 {
+        if length == k {
+            return if self.0[current][start] != 0 { 1 } else { 0 };
+        }
+
+        let mut count: u64 = 0;
+        for next in (start + 1)..N {
+            if self.0[current][next] != 0 && !visited[next] {
+                visited[next] = true;
+                count += self.extend_cycles(start, next, length + 1, k, visited);
+                visited[next] = false;
+            }
+        }
+
+        count
+    }
+}
@@ -0,0 +1,377 @@
+use std::fmt;
+use std::error::Error;
+use std::str::FromStr;
+
+use num::Num;
+
+use crate::matrix::Matrix;
+use crate::dynamic::DynMatrix;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixFormatError {
+    Parse { line: usize, column: usize, message: String }, // both count from 1, column in characters
+    SizeMismatch { expected: (usize, usize), found: (usize, usize) } // (rows, columns)
+}
+impl fmt::Display for MatrixFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MatrixFormatError::Parse { line, column, message } => write!(f, "line {}, column {}: {}", line, column, message),
+            MatrixFormatError::SizeMismatch { expected, found } => write!(f, "expected a {}x{} matrix, found {}x{}", expected.0, expected.1, found.0, found.1)
+        }
+    }
+}
+impl Error for MatrixFormatError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Layout {
+    Coordinate, // only the non-zero entries, as "row column value"
+    Array // every entry, column by column
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Field {
+    Real,
+    Integer,
+    Pattern // coordinate only, every listed entry reads as one
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Symmetry {
+    General,
+    Symmetric // only the lower triangle is stored
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MatrixMarketFormat {
+    pub layout: Layout,
+    pub field: Field,
+    pub symmetry: Symmetry
+}
+impl Default for MatrixMarketFormat {
+    fn default() -> Self {
+        MatrixMarketFormat { layout: Layout::Coordinate, field: Field::Real, symmetry: Symmetry::General }
+    }
+}
+
+fn parse_error(line: usize, column: usize, message: String) -> MatrixFormatError {
+    MatrixFormatError::Parse { line, column, message }
+}
+
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    // whitespace separated tokens with the column each one starts at
+    let mut result: Vec<(usize, &str)> = Vec::new();
+    let mut start: Option<usize> = None;
+    for (index, c) in line.char_indices() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some(index),
+            (Some(first), true) => {
+                result.push((first, &line[first..index]));
+                start = None;
+            },
+            _ => ()
+        }
+    }
+    if let Some(first) = start {
+        result.push((first, &line[first..]));
+    }
+
+    result.into_iter().map(|(index, token)| (line[..index].chars().count() + 1, token)).collect()
+}
+
+fn parse_value<T: FromStr>(token: &str, line: usize, column: usize, what: &str) -> Result<T, MatrixFormatError> {
+    token.parse().map_err(|_| parse_error(line, column, format!("expected {}, found '{}'", what, token)))
+}
+
+fn parse_index(token: &str, line: usize, column: usize, bound: usize) -> Result<usize, MatrixFormatError> {
+    // 1-based in the file, 0-based in the result
+    let index: usize = parse_value(token, line, column, "an index")?;
+    if index == 0 || index > bound {
+        return Err(parse_error(line, column, format!("index {} is outside 1..={}", index, bound)));
+    }
+
+    Ok(index - 1)
+}
+
+pub const MAX_ENTRIES: usize = 1 << 26; // the largest runtime sized matrix the readers will allocate
+
+pub(crate) type Entry<F> = (usize, usize, F); // row, column, value
+
+pub(crate) fn parse_matrix_market<F: Num + Copy + FromStr>(text: &str, expected: Option<(usize, usize)>) -> Result<(usize, usize, Vec<Entry<F>>), MatrixFormatError> {
+    // the size and the entries that were listed, the size line is checked against the expected
+    // shape, or against MAX_ENTRIES without one, before anything is read, so a file claiming
+    // to be huge costs no more memory than it takes up
+    let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line));
+
+    let header: &str = match lines.next() {
+        Some((_, header)) => header,
+        None => return Err(parse_error(1, 1, String::from("missing %%MatrixMarket header")))
+    };
+    let banner: Vec<(usize, &str)> = tokens(header);
+    if banner.len() != 5 || !banner[0].1.eq_ignore_ascii_case("%%MatrixMarket") || !banner[1].1.eq_ignore_ascii_case("matrix") {
+        return Err(parse_error(1, 1, String::from("expected '%%MatrixMarket matrix <format> <field> <symmetry>'")));
+    }
+
+    let layout: Layout = match banner[2].1.to_lowercase().as_str() {
+        "coordinate" => Layout::Coordinate,
+        "array" => Layout::Array,
+        format => return Err(parse_error(1, banner[2].0, format!("unsupported format '{}'", format)))
+    };
+    let field: Field = match banner[3].1.to_lowercase().as_str() {
+        "real" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" if layout == Layout::Coordinate => Field::Pattern,
+        field => return Err(parse_error(1, banner[3].0, format!("unsupported field '{}'", field)))
+    };
+    let symmetry: Symmetry = match banner[4].1.to_lowercase().as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        symmetry => return Err(parse_error(1, banner[4].0, format!("unsupported symmetry '{}'", symmetry)))
+    };
+
+    let mut content = lines.filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('%'));
+
+    let (size_line, size_text) = match content.next() {
+        Some(found) => found,
+        None => return Err(parse_error(text.lines().count() + 1, 1, String::from("missing size line")))
+    };
+    let size: Vec<(usize, &str)> = tokens(size_text);
+    let expected_fields: usize = if layout == Layout::Coordinate { 3 } else { 2 };
+    if size.len() != expected_fields {
+        let message: &str = if layout == Layout::Coordinate { "expected 'rows columns entries'" } else { "expected 'rows columns'" };
+        return Err(parse_error(size_line, 1, String::from(message)));
+    }
+    let rows: usize = parse_value(size[0].1, size_line, size[0].0, "a row count")?;
+    let columns: usize = parse_value(size[1].1, size_line, size[1].0, "a column count")?;
+    if symmetry == Symmetry::Symmetric && rows != columns {
+        return Err(parse_error(size_line, 1, String::from("a symmetric matrix has to be square")));
+    }
+
+    match expected {
+        Some(shape) if shape != (rows, columns) => return Err(MatrixFormatError::SizeMismatch { expected: shape, found: (rows, columns) }),
+        None if rows.checked_mul(columns).is_none_or(|count| count > MAX_ENTRIES) => {
+            return Err(parse_error(size_line, 1, format!("a {}x{} matrix has more than the {} entries that can be read", rows, columns, MAX_ENTRIES)));
+        },
+        _ => ()
+    }
+
+    let mut result: Vec<Entry<F>> = Vec::new();
+    let mut last_line: usize = size_line;
+    match layout {
+        Layout::Coordinate => {
+            let entries: usize = parse_value(size[2].1, size_line, size[2].0, "an entry count")?;
+            let mut found: usize = 0;
+            for (line_number, line) in content {
+                last_line = line_number;
+                let fields: Vec<(usize, &str)> = tokens(line);
+                let expected: usize = if field == Field::Pattern { 2 } else { 3 };
+                if fields.len() != expected {
+                    return Err(parse_error(line_number, 1, format!("expected {} fields, found {}", expected, fields.len())));
+                }
+
+                let i: usize = parse_index(fields[0].1, line_number, fields[0].0, rows)?;
+                let j: usize = parse_index(fields[1].1, line_number, fields[1].0, columns)?;
+                let value: F = match field {
+                    Field::Pattern => F::one(),
+                    _ => parse_value(fields[2].1, line_number, fields[2].0, "a number")?
+                };
+
+                result.push((i, j, value));
+                if symmetry == Symmetry::Symmetric {
+                    result.push((j, i, value));
+                }
+                found += 1;
+            }
+
+            if found != entries {
+                return Err(parse_error(last_line, 1, format!("expected {} entries, found {}", entries, found)));
+            }
+        },
+        Layout::Array => {
+            // column major, only the lower triangle of a symmetric matrix
+            let mut positions = (0..columns).flat_map(|j| {
+                let first: usize = if symmetry == Symmetry::Symmetric { j } else { 0 };
+                (first..rows).map(move |i| (i, j))
+            });
+            let expected: usize = positions.clone().count();
+
+            let mut found: usize = 0;
+            for (line_number, line) in content {
+                last_line = line_number;
+                for (column, token) in tokens(line) {
+                    let (i, j) = match positions.next() {
+                        Some(position) => position,
+                        None => return Err(parse_error(line_number, column, format!("expected {} entries, found more", expected)))
+                    };
+
+                    let value: F = parse_value(token, line_number, column, "a number")?;
+                    result.push((i, j, value));
+                    if symmetry == Symmetry::Symmetric {
+                        result.push((j, i, value));
+                    }
+                    found += 1;
+                }
+            }
+
+            if found != expected {
+                return Err(parse_error(last_line, 1, format!("expected {} entries, found {}", expected, found)));
+            }
+        }
+    }
+
+    Ok((rows, columns, result))
+}
+
+pub(crate) fn parse_delimited<F: Num + Copy + FromStr>(text: &str, delimiter: char, header: bool) -> Result<Vec<Vec<F>>, MatrixFormatError> {
+    // every row has to have as many fields as the first one, blank lines are skipped
+    let mut result: Vec<Vec<F>> = Vec::new();
+    let mut lines = text.lines().enumerate().map(|(index, line)| (index + 1, line)).filter(|(_, line)| !line.trim().is_empty());
+    if header {
+        lines.next();
+    }
+
+    for (line_number, line) in lines {
+        let mut row: Vec<F> = Vec::new();
+        let mut column: usize = 1;
+        for field in line.split(delimiter) {
+            let leading: usize = field.chars().take_while(|c| c.is_whitespace()).count();
+            let value: &str = field.trim();
+            let value: &str = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+            row.push(parse_value(value, line_number, column + leading, "a number")?);
+            column += field.chars().count() + 1;
+        }
+
+        if let Some(first) = result.first() {
+            if row.len() != first.len() {
+                return Err(parse_error(line_number, 1, format!("expected {} fields, found {}", first.len(), row.len())));
+            }
+        }
+        result.push(row);
+    }
+
+    Ok(result)
+}
+
+fn check_size<F: Num + Copy, const R: usize, const C: usize>(rows: Vec<Vec<F>>, columns: usize) -> Result<Matrix<F, R, C>, MatrixFormatError> {
+    if rows.len() != R || columns != C {
+        return Err(MatrixFormatError::SizeMismatch { expected: (R, C), found: (rows.len(), columns) });
+    }
+
+    let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+    for i in 0..R {
+        result[i].copy_from_slice(&rows[i]);
+    }
+
+    Ok(Matrix::from(result))
+}
+
+impl<F: Num + Copy + FromStr, const R: usize, const C: usize> Matrix<F, R, C> {
+    pub fn from_matrix_market(text: &str) -> Result<Matrix<F, R, C>, MatrixFormatError> {
+        let (_, _, entries) = parse_matrix_market(text, Some((R, C)))?;
+        let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+        for (i, j, value) in entries {
+            result[i][j] = value;
+        }
+
+        Ok(Matrix::from(result))
+    }
+
+    pub fn from_csv(text: &str, delimiter: char, header: bool) -> Result<Matrix<F, R, C>, MatrixFormatError> {
+        // header skips the first line, use '\t' for TSV
+        let rows: Vec<Vec<F>> = parse_delimited(text, delimiter, header)?;
+        let columns: usize = rows.first().map_or(C, |row| row.len());
+        check_size(rows, columns)
+    }
+}
+
+impl<F: Num + Copy + FromStr> DynMatrix<F> {
+    pub fn from_matrix_market(text: &str) -> Result<DynMatrix<F>, MatrixFormatError> {
+        let (rows, columns, entries) = parse_matrix_market(text, None)?;
+        let mut result: DynMatrix<F> = DynMatrix::zeros(rows, columns);
+        for (i, j, value) in entries {
+            result[i][j] = value;
+        }
+
+        Ok(result)
+    }
+
+    pub fn from_csv(text: &str, delimiter: char, header: bool) -> Result<DynMatrix<F>, MatrixFormatError> {
+        // parse_delimited already rejects ragged rows
+        let rows: Vec<Vec<F>> = parse_delimited(text, delimiter, header)?;
+        Ok(DynMatrix::from_rows(rows, 0).unwrap())
+    }
+}
+
+impl<F: Num + Copy + fmt::Display, const R: usize, const C: usize> Matrix<F, R, C> {
+    pub fn to_matrix_market(&self, format: MatrixMarketFormat) -> String {
+        // entries go out column by column, which is the order the format expects for arrays
+        let symmetric: bool = format.symmetry == Symmetry::Symmetric;
+        if symmetric {
+            assert!(R == C, "only square matrices can be written as symmetric");
+            for i in 0..R {
+                for j in 0..i {
+                    assert!(self[i][j] == self[j][i], "the matrix is not symmetric");
+                }
+            }
+        }
+
+        let layout: &str = match format.layout {
+            Layout::Coordinate => "coordinate",
+            Layout::Array => "array"
+        };
+        let field: &str = match format.field {
+            Field::Real => "real",
+            Field::Integer => "integer",
+            Field::Pattern => {
+                assert!(format.layout == Layout::Coordinate, "the pattern field needs the coordinate layout");
+                "pattern"
+            }
+        };
+        let symmetry: &str = if symmetric { "symmetric" } else { "general" };
+
+        let mut entries: Vec<String> = Vec::new();
+        for j in 0..C {
+            let first: usize = if symmetric { j } else { 0 };
+            for i in first..R {
+                match (format.layout, format.field) {
+                    (Layout::Array, _) => entries.push(self[i][j].to_string()),
+                    (Layout::Coordinate, _) if self[i][j] == F::zero() => (),
+                    (Layout::Coordinate, Field::Pattern) => entries.push(format!("{} {}", i + 1, j + 1)),
+                    (Layout::Coordinate, _) => entries.push(format!("{} {} {}", i + 1, j + 1, self[i][j]))
+                }
+            }
+        }
+
+        let mut result: String = format!("%%MatrixMarket matrix {} {} {}\n", layout, field, symmetry);
+        match format.layout {
+            Layout::Coordinate => result += &format!("{} {} {}\n", R, C, entries.len()),
+            Layout::Array => result += &format!("{} {}\n", R, C)
+        }
+        for entry in entries {
+            result += &entry;
+            result += "\n";
+        }
+
+        result
+    }
+
+    pub fn to_csv(&self, delimiter: char, header: Option<&[&str]>) -> String {
+        // header names the columns, quoted when they contain the delimiter
+        let mut result: String = String::new();
+        if let Some(names) = header {
+            assert_eq!(names.len(), C, "the header needs one name per column");
+            let names: Vec<String> = names.iter().map(|name| {
+                if name.contains(delimiter) || name.contains('"') { format!("\"{}\"", name.replace('"', "\"\"")) } else { name.to_string() }
+            }).collect();
+            result += &names.join(&delimiter.to_string());
+            result += "\n";
+        }
+
+        for i in 0..R {
+            let row: Vec<String> = self[i].iter().map(|entry| entry.to_string()).collect();
+            result += &row.join(&delimiter.to_string());
+            result += "\n";
+        }
+
+        result
+    }
+}
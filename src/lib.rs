@@ -1,4 +1,14 @@
 pub mod vector;
 pub mod matrix;
 pub mod polynomial;
-pub mod graph;
\ No newline at end of file
+pub mod orthogonal;
+pub mod graph;
+pub mod markov;
+pub mod io;
+pub mod npy;
+pub mod display;
+pub mod parse;
+pub mod dynamic;
+pub mod parallel;
+#[cfg(feature = "serde")]
+pub mod serialize;
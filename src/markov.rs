@@ -0,0 +1,341 @@
+use std::fmt;
+use std::error::Error;
+use std::collections::VecDeque;
+
+use crate::graph::Graph;
+use crate::matrix::{Matrix, solve_rows};
+use crate::vector::Vector;
+
+const TOLERANCE: f64 = 1e-9;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StochasticError {
+    NegativeEntry { row: usize, column: usize }, // also NaN entries
+    RowSum { row: usize, sum: f64 } // every row has to add up to 1
+}
+impl fmt::Display for StochasticError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StochasticError::NegativeEntry { row, column } => write!(f, "negative or NaN transition probability at ({}, {})", row, column),
+            StochasticError::RowSum { row, sum } => write!(f, "row {} sums to {} instead of 1", row, sum)
+        }
+    }
+}
+impl Error for StochasticError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommunicatingClass {
+    pub states: Vec<usize>, // in increasing order
+    pub closed: bool, // no way out, which for a finite chain means recurrent rather than transient
+    pub period: usize
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Absorption<const N: usize> {
+    absorbing: [bool; N],
+    fundamental: [[f64; N]; N],
+    probabilities: [[f64; N]; N]
+}
+impl<const N: usize> Absorption<N> {
+    pub fn absorbing_states(&self) -> Vec<usize> {
+        (0..N).filter(|&i| self.absorbing[i]).collect()
+    }
+
+    pub fn fundamental_matrix(&self) -> Matrix<f64, N, N> {
+        // (I - Q)^-1 over the transient states, the expected number of visits to j starting from i,
+        // rows and columns of absorbing states are zero
+        Matrix::from(self.fundamental)
+    }
+
+    pub fn expected_steps(&self) -> Vector<f64, N> {
+        // steps until absorption from each state, zero for the absorbing ones
+        let mut result: [f64; N] = [0.0; N];
+        for i in 0..N {
+            result[i] = self.fundamental[i].iter().sum();
+        }
+
+        Vector::from(result)
+    }
+
+    pub fn probabilities(&self) -> Matrix<f64, N, N> {
+        // the chance that starting from i the chain ends up in absorbing state j
+        Matrix::from(self.probabilities)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MarkovChain<const N: usize> (Matrix<f64, N, N>); // row stochastic, entry (i, j) is the chance of moving from i to j
+impl<const N: usize> TryFrom<Matrix<f64, N, N>> for MarkovChain<N> {
+    type Error = StochasticError;
+
+    fn try_from(matrix: Matrix<f64, N, N>) -> Result<Self, Self::Error> {
+        MarkovChain::new(matrix)
+    }
+}
+impl<const N: usize> Into<Matrix<f64, N, N>> for MarkovChain<N> {
+    fn into(self) -> Matrix<f64, N, N> {
+        self.0
+    }
+}
+
+impl<const N: usize> MarkovChain<N> {
+    pub fn new(matrix: Matrix<f64, N, N>) -> Result<MarkovChain<N>, StochasticError> {
+        // NaN compares false with everything, so it needs its own check
+        for i in 0..N {
+            for j in 0..N {
+                if matrix[i][j] < 0.0 || matrix[i][j].is_nan() {
+                    return Err(StochasticError::NegativeEntry { row: i, column: j });
+                }
+            }
+
+            let sum: f64 = matrix[i].iter().sum();
+            if (sum - 1.0).abs() > TOLERANCE || sum.is_nan() {
+                return Err(StochasticError::RowSum { row: i, sum });
+            }
+        }
+
+        Ok(MarkovChain(matrix))
+    }
+
+    pub fn random_walk(graph: &Graph<N>) -> MarkovChain<N> {
+        // follows an out edge chosen in proportion to its multiplicity, vertices without any stay put
+        let adjacency: Matrix<u8, N, N> = (*graph).into();
+        let mut result: [[f64; N]; N] = [[0.0; N]; N];
+        for i in 0..N {
+            let out_degree: f64 = adjacency[i].iter().map(|&count| f64::from(count)).sum();
+            if out_degree == 0.0 {
+                result[i][i] = 1.0;
+                continue;
+            }
+
+            for j in 0..N {
+                result[i][j] = f64::from(adjacency[i][j]) / out_degree;
+            }
+        }
+
+        MarkovChain(Matrix::from(result))
+    }
+
+    pub fn google(graph: &Graph<N>, damping: f64) -> MarkovChain<N> {
+        // with probability damping follow an out edge, otherwise jump anywhere,
+        // dangling vertices always jump
+        assert!((0.0..=1.0).contains(&damping), "damping has to be a probability");
+
+        let adjacency: Matrix<u8, N, N> = (*graph).into();
+        let teleport: f64 = (1.0 - damping) / N as f64;
+        let mut result: [[f64; N]; N] = [[0.0; N]; N];
+        for i in 0..N {
+            let out_degree: f64 = adjacency[i].iter().map(|&count| f64::from(count)).sum();
+            for j in 0..N {
+                result[i][j] = if out_degree == 0.0 {
+                    1.0 / N as f64
+                } else {
+                    teleport + (damping * f64::from(adjacency[i][j]) / out_degree)
+                };
+            }
+        }
+
+        MarkovChain(Matrix::from(result))
+    }
+
+    pub fn transition_matrix(&self) -> Matrix<f64, N, N> {
+        self.0
+    }
+
+    pub fn probability(&self, from: usize, to: usize) -> f64 {
+        self.0[from][to]
+    }
+
+    pub fn n_step(&self, steps: u32) -> Matrix<f64, N, N> {
+        self.0.pow(steps)
+    }
+
+    pub fn distribution_after(&self, initial: Vector<f64, N>, steps: u32) -> Vector<f64, N> {
+        // distributions are row vectors, so each step is initial P
+        let mut current: Vector<f64, N> = initial;
+        for _ in 0..steps {
+            let mut next: [f64; N] = [0.0; N];
+            for i in 0..N {
+                for j in 0..N {
+                    next[j] += current[i] * self.0[i][j];
+                }
+            }
+            current = Vector::from(next);
+        }
+
+        current
+    }
+
+    fn transition_graph(&self) -> Graph<N> {
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for i in 0..N {
+            for j in 0..N {
+                if self.0[i][j] > 0.0 {
+                    edges.push((i, j));
+                }
+            }
+        }
+
+        Graph::from_edges(&edges)
+    }
+
+    pub fn communicating_classes(&self) -> Vec<CommunicatingClass> {
+        // the strongly connected components of the transition graph, ordered by their smallest state
+        let graph: Graph<N> = self.transition_graph();
+        let mut classes: Vec<CommunicatingClass> = Vec::new();
+        for mut states in graph.strongly_connected_components() {
+            states.sort();
+
+            let mut member: [bool; N] = [false; N];
+            for &s in &states {
+                member[s] = true;
+            }
+
+            let closed: bool = states.iter().all(|&s| graph.neighbors(s).all(|t| member[t]));
+            let period: usize = class_period(&graph, &states, &member);
+            classes.push(CommunicatingClass { states, closed, period });
+        }
+        classes.sort_by_key(|class| class.states[0]);
+
+        classes
+    }
+
+    pub fn is_irreducible(&self) -> bool {
+        self.communicating_classes().len() == 1
+    }
+
+    pub fn is_absorbing_state(&self, state: usize) -> bool {
+        // within the same tolerance new allows for the row sums
+        (self.0[state][state] - 1.0).abs() <= TOLERANCE
+    }
+
+    pub fn stationary_distributions(&self) -> Vec<Vector<f64, N>> {
+        // one per closed class, supported on that class, every stationary distribution is a mixture of these
+        let mut result: Vec<Vector<f64, N>> = Vec::new();
+        for class in self.communicating_classes() {
+            if !class.closed {
+                continue;
+            }
+
+            // pi (P - I) = 0 restricted to the class, with the last equation swapped for sum pi = 1
+            let m: usize = class.states.len();
+            let mut system: Vec<Vec<f64>> = vec![vec![0.0; m + 1]; m];
+            for (row, &j) in class.states.iter().enumerate() {
+                for (column, &i) in class.states.iter().enumerate() {
+                    system[row][column] = self.0[i][j] - if i == j { 1.0 } else { 0.0 };
+                }
+            }
+            system[m - 1] = vec![1.0; m + 1];
+
+            let solution: Vec<f64> = solve_rows(system).expect("an irreducible chain has exactly one stationary distribution");
+            let mut distribution: [f64; N] = [0.0; N];
+            for (k, &s) in class.states.iter().enumerate() {
+                distribution[s] = solution[k];
+            }
+            result.push(Vector::from(distribution));
+        }
+
+        result
+    }
+
+    pub fn stationary_distribution(&self) -> Option<Vector<f64, N>> {
+        // None unless the stationary distribution is unique, that is, there is exactly one closed class
+        let mut distributions: Vec<Vector<f64, N>> = self.stationary_distributions();
+        if distributions.len() != 1 {
+            return None;
+        }
+
+        distributions.pop()
+    }
+
+    pub fn absorption(&self) -> Option<Absorption<N>> {
+        // None unless every state can reach an absorbing one, otherwise the fundamental matrix does not exist
+        let mut absorbing: [bool; N] = [false; N];
+        for i in 0..N {
+            absorbing[i] = self.is_absorbing_state(i);
+        }
+
+        let graph: Graph<N> = self.transition_graph();
+        for i in 0..N {
+            if !graph.dfs(i).any(|v| absorbing[v]) {
+                return None;
+            }
+        }
+
+        let transient: Vec<usize> = (0..N).filter(|&i| !absorbing[i]).collect();
+        let t: usize = transient.len();
+
+        // solve (I - Q) x = e_k for every column of the fundamental matrix
+        let mut fundamental: [[f64; N]; N] = [[0.0; N]; N];
+        for (k, &column) in transient.iter().enumerate() {
+            let mut system: Vec<Vec<f64>> = vec![vec![0.0; t + 1]; t];
+            for (row, &i) in transient.iter().enumerate() {
+                for (col, &j) in transient.iter().enumerate() {
+                    system[row][col] = if i == j { 1.0 } else { 0.0 } - self.0[i][j];
+                }
+                system[row][t] = if row == k { 1.0 } else { 0.0 };
+            }
+
+            let solution: Vec<f64> = solve_rows(system)?;
+            for (row, &i) in transient.iter().enumerate() {
+                fundamental[i][column] = solution[row];
+            }
+        }
+
+        // B = N R for the transient rows, absorbing states are absorbed where they start
+        let mut probabilities: [[f64; N]; N] = [[0.0; N]; N];
+        for j in 0..N {
+            if !absorbing[j] {
+                continue;
+            }
+
+            probabilities[j][j] = 1.0;
+            for &i in &transient {
+                for &k in &transient {
+                    probabilities[i][j] += fundamental[i][k] * self.0[k][j];
+                }
+            }
+        }
+
+        Some(Absorption { absorbing, fundamental, probabilities })
+    }
+}
+
+fn class_period<const N: usize>(graph: &Graph<N>, states: &[usize], member: &[bool; N]) -> usize {
+    // the gcd of level[u] + 1 - level[v] over the edges inside the class, from breadth first levels,
+    // a class without any internal edge (a single transient state) gets period 0
+    let mut level: [Option<usize>; N] = [None; N];
+    level[states[0]] = Some(0);
+    let mut queue: VecDeque<usize> = VecDeque::from([states[0]]);
+    let mut period: usize = 0;
+    while let Some(u) = queue.pop_front() {
+        let depth: usize = level[u].unwrap();
+        for v in graph.neighbors(u).filter(|&v| member[v]) {
+            match level[v] {
+                Some(other) => period = gcd(period, (depth + 1).abs_diff(other)),
+                None => {
+                    level[v] = Some(depth + 1);
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+
+    period
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+impl<const N: usize> Graph<N> {
+    pub fn pagerank(&self, damping: f64) -> Vector<f64, N> {
+        // the stationary distribution of the Google chain, damping is usually 0.85
+        assert!(damping < 1.0, "pagerank needs damping below 1 for a unique ranking");
+        if N == 0 {
+            // no states, no closed class and nothing to rank
+            return Vector::from([0.0; N]);
+        }
+        MarkovChain::google(self, damping).stationary_distribution().unwrap()
+    }
+}
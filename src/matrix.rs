@@ -1,7 +1,9 @@
-use num::Num;
+use num::{Float, Num};
+use num::complex::Complex;
 use std::ops;
 use crate::vector::Vector;
 use crate::vector::dot;
+use crate::parallel::{Element, chunks};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct Matrix<F: Num + Copy, const R: usize, const C: usize> ([[F; C]; R]); 
@@ -26,35 +28,35 @@ impl<F: Num + Copy, const R: usize, const C: usize> Into<[[F; C]; R]> for Matrix
         self.0
     }
 }
-impl<F: Num + Copy, const R: usize, const C: usize> ops::Add<Matrix<F, R, C>> for Matrix<F, R, C> {
+impl<F: Num + Copy + Element, const R: usize, const C: usize> ops::Add<Matrix<F, R, C>> for Matrix<F, R, C> {
     type Output = Matrix<F, R, C>;
 
     fn add(self, right: Matrix<F, R, C>) -> Matrix<F, R, C> {
         madd(self, right)
     }
 }
-impl<F: Num + Copy, const R: usize, const C: usize> ops::Sub<Matrix<F, R, C>> for Matrix<F, R, C> {
+impl<F: Num + Copy + Element, const R: usize, const C: usize> ops::Sub<Matrix<F, R, C>> for Matrix<F, R, C> {
     type Output = Matrix<F, R, C>;
 
     fn sub(self, right: Matrix<F, R, C>) -> Matrix<F, R, C> {
         msub(self, right)
     }
 }
-impl<F: Num + Copy, const R: usize, const C: usize> ops::Mul<F> for Matrix<F, R, C> {
+impl<F: Num + Copy + Element, const R: usize, const C: usize> ops::Mul<F> for Matrix<F, R, C> {
     type Output = Matrix<F, R, C>;
 
     fn mul(self, left: F) -> Matrix<F, R, C> {
         mscale(left, self)
     }
 }
-impl<F: Num + Copy, const R: usize, const C: usize> ops::Div<F> for Matrix<F, R, C> {
+impl<F: Num + Copy + Element, const R: usize, const C: usize> ops::Div<F> for Matrix<F, R, C> {
     type Output = Matrix<F, R, C>;
 
     fn div(self, left: F) -> Matrix<F, R, C> {
         mdiv(left, self)
     }
 }
-impl<F: Num + Copy, const L: usize, const M: usize, const N: usize> ops::Mul<Matrix<F, M, N>> for Matrix<F, L, M> {
+impl<F: Num + Copy + Element, const L: usize, const M: usize, const N: usize> ops::Mul<Matrix<F, M, N>> for Matrix<F, L, M> {
     type Output = Matrix<F, L, N>;
 
     fn mul(self, right: Matrix<F, M, N>) -> Matrix<F, L, N> {
@@ -103,6 +105,7 @@ impl<F: Num + Copy, const R: usize, const C: usize> Matrix<F, R, C> {
         Matrix::from(result)
     }
 
+    #[allow(clippy::clone_on_copy, clippy::manual_swap)]
     pub fn swap(&self, first_row: usize, second_row: usize) -> Matrix<F, R, C> { // could improve function by not using temp and cloning from rows of self
         let mut result: [[F; C]; R] = self.0.clone();
         let temp: [F; C] = result[first_row];
@@ -112,7 +115,8 @@ impl<F: Num + Copy, const R: usize, const C: usize> Matrix<F, R, C> {
         Matrix::from(result)
     }
 
-    pub fn reduce(&self) -> Matrix<F, R, C> { // I am getting some pretty serious error with this function
+    #[allow(clippy::clone_on_copy, clippy::manual_swap)]
+    pub fn reduce(&self) -> Matrix<F, R, C> where F: Element { // I am getting some pretty serious error with this function
         // I considered a recursive approach to this, 
         // but the conceptual simplicity comes at the cost of performance.
 
@@ -149,17 +153,16 @@ impl<F: Num + Copy, const R: usize, const C: usize> Matrix<F, R, C> {
                 result[row][j] = result[row][j] / scale;
             }
 
-            // eliminate the column from all other rows
-            for i in 0..R {
-                if i == row {
-                    continue;
-                }
-
-                let scale: F = result[i][col];
-                for j in col..C { // start at pivot column because all columns before are 0
-                    result[i][j] = result[i][j] - (scale * result[row][j]);
+            // eliminate the column from all other rows, which are independent of each other
+            let pivot: [F; C] = result[row];
+            chunks(result.as_flattened_mut(), C, R * (C - col), |i, other| {
+                if i != row {
+                    let scale: F = other[col];
+                    for j in col..C { // start at pivot column because all columns before are 0
+                        other[j] = other[j] - (scale * pivot[j]);
+                    }
                 }
-            }
+            });
 
             row += 1;
         }
@@ -167,7 +170,7 @@ impl<F: Num + Copy, const R: usize, const C: usize> Matrix<F, R, C> {
         Matrix::from(result)
     }
 
-    pub fn rank(&self) -> usize {
+    pub fn rank(&self) -> usize where F: Element {
         let is_empty = |row: [F; C]| -> bool { // idk if this is how you are supposed to use closures but whatever
             for i in row {
                 if i != F::zero() {
@@ -193,46 +196,111 @@ impl<F: Num + Copy, const R: usize, const C: usize> Matrix<F, R, C> {
     }
 }
 
-pub fn mscale<F: Num + Copy, const R: usize, const C: usize>(scalar: F, matrix: Matrix<F, R, C>) -> Matrix<F, R, C> { 
+impl<F: Num + Copy, const N: usize> Matrix<F, N, N> {
+    pub fn pow(&self, exponent: u32) -> Matrix<F, N, N> where F: Element {
+        // exponentiation by squaring
+        let mut result: Matrix<F, N, N> = identity();
+        let mut base: Matrix<F, N, N> = *self;
+        let mut exponent: u32 = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = mmul(result, base);
+            }
+
+            exponent >>= 1;
+            if exponent > 0 {
+                base = mmul(base, base);
+            }
+        }
+
+        result
+    }
+
+    pub fn determinant(&self) -> F {
+        // Bareiss elimination, every division is exact so integer types get the exact answer
+        let mut result: [[F; N]; N] = self.0;
+        let mut negate: bool = false;
+        let mut previous: F = F::one();
+
+        if N == 0 {
+            return F::one();
+        }
+
+        for k in 0..(N - 1) {
+            if result[k][k] == F::zero() {
+                // swap in a row with a non-zero pivot, or the determinant is zero
+                let mut pivot_row: usize = N; // invalid row
+                for i in (k + 1)..N {
+                    if result[i][k] != F::zero() {
+                        pivot_row = i;
+                        break;
+                    }
+                }
+
+                if pivot_row == N {
+                    return F::zero();
+                }
+
+                result.swap(k, pivot_row);
+                negate = !negate;
+            }
+
+            for i in (k + 1)..N {
+                for j in (k + 1)..N {
+                    result[i][j] = ((result[i][j] * result[k][k]) - (result[i][k] * result[k][j])) / previous;
+                }
+            }
+            previous = result[k][k];
+        }
+
+        match negate {
+            true => F::zero() - result[N - 1][N - 1],
+            false => result[N - 1][N - 1]
+        }
+    }
+}
+
+pub fn mscale<F: Num + Copy + Element, const R: usize, const C: usize>(scalar: F, matrix: Matrix<F, R, C>) -> Matrix<F, R, C> { 
     let mut result: [[F; C]; R] = [[F::zero(); C]; R];
-    for i in 0..R {
+    chunks(result.as_flattened_mut(), C, R * C, |i, row| {
         for j in 0..C {
-            result[i][j] = scalar * matrix[i][j];
+            row[j] = scalar * matrix[i][j];
         }
-    }
+    });
 
     Matrix::from(result)
 }
 
-pub fn mdiv<F: Num + Copy, const R: usize, const C: usize>(scalar: F, matrix: Matrix<F, R, C>) -> Matrix<F, R, C> { 
+pub fn mdiv<F: Num + Copy + Element, const R: usize, const C: usize>(scalar: F, matrix: Matrix<F, R, C>) -> Matrix<F, R, C> { 
     let mut result: [[F; C]; R] = [[F::zero(); C]; R];
-    for i in 0..R {
+    chunks(result.as_flattened_mut(), C, R * C, |i, row| {
         for j in 0..C {
-            result[i][j] = matrix[i][j] / scalar;
+            row[j] = matrix[i][j] / scalar;
         }
-    }
+    });
 
     Matrix::from(result)
 }
 
-pub fn madd<F: Num + Copy, const R: usize, const C: usize>(left: Matrix<F, R, C>, right: Matrix<F, R, C>) -> Matrix<F, R, C> { 
+pub fn madd<F: Num + Copy + Element, const R: usize, const C: usize>(left: Matrix<F, R, C>, right: Matrix<F, R, C>) -> Matrix<F, R, C> { 
     let mut result: [[F; C]; R] = [[F::zero(); C]; R];
-    for i in 0..R {
+    chunks(result.as_flattened_mut(), C, R * C, |i, row| {
         for j in 0..C {
-            result[i][j] = left[i][j] + right[i][j];
+            row[j] = left[i][j] + right[i][j];
         }
-    }
+    });
 
     Matrix::from(result)
 }
 
-pub fn msub<F: Num + Copy, const R: usize, const C: usize>(left: Matrix<F, R, C>, right: Matrix<F, R, C>) -> Matrix<F, R, C> { 
+pub fn msub<F: Num + Copy + Element, const R: usize, const C: usize>(left: Matrix<F, R, C>, right: Matrix<F, R, C>) -> Matrix<F, R, C> { 
     let mut result: [[F; C]; R] = [[F::zero(); C]; R];
-    for i in 0..R {
+    chunks(result.as_flattened_mut(), C, R * C, |i, row| {
         for j in 0..C {
-            result[i][j] = left[i][j] - right[i][j];
+            row[j] = left[i][j] - right[i][j];
         }
-    }
+    });
 
     Matrix::from(result)
 }
@@ -272,14 +340,164 @@ pub fn vaugment<F: Num + Copy, const R: usize, const C: usize, const COUT: usize
     Matrix::from(result)
 }
 
-pub fn mmul<F: Num + Copy, const L: usize, const M: usize, const N: usize>(left: Matrix<F, L, M>, right: Matrix<F, M, N>) -> Matrix<F, L, N> { // try strassen for large?
-    let mut result:[[F; N]; L] = [[F::zero(); N]; L];
-    for i in 0..N {
-        for j in 0..L {
-            result[i][j] = dot(left.get_row(i), right.get_column(j));
+pub fn kronecker<F: Num + Copy, const R1: usize, const C1: usize, const R2: usize, const C2: usize, const ROUT: usize, const COUT: usize> (left: Matrix<F, R1, C1>, right: Matrix<F, R2, C2>) -> Matrix<F, ROUT, COUT> { // :(
+    // same story as maugment, the output size has to be spelled out
+    const { assert!((R1 * R2) == ROUT && (C1 * C2) == COUT) }
+
+    let mut result: [[F; COUT]; ROUT] = [[F::zero(); COUT]; ROUT];
+    for i in 0..R1 {
+        for j in 0..C1 {
+            for k in 0..R2 {
+                for l in 0..C2 {
+                    result[(i * R2) + k][(j * C2) + l] = left[i][j] * right[k][l];
+                }
+            }
         }
     }
-        
+
+    Matrix::from(result)
+}
+
+const BLOCK: usize = 64; // a 64x64 tile of f64 is 32 KiB, about one L1 cache
+
+pub(crate) fn multiply_into<F: Num + Copy>(left: &[F], right: &[F], result: &mut [F], (l, m, n): (usize, usize, usize)) {
+    // result += left * right for row major l x m and m x n slices, in tiles so a block of right
+    // stays in cache while every row of left passes over it, the ikj order makes the innermost
+    // loop run along a row of right and a row of result, which the compiler vectorizes for f32 and f64
+    assert_eq!((left.len(), right.len(), result.len()), (l * m, m * n, l * n), "the slices do not match the dimensions");
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    for k0 in (0..m).step_by(BLOCK) {
+        let k1: usize = (k0 + BLOCK).min(m);
+        for j0 in (0..n).step_by(BLOCK) {
+            let j1: usize = (j0 + BLOCK).min(n);
+            for (output, left_row) in result.chunks_exact_mut(n).zip(left.chunks_exact(m)) {
+                let output: &mut [F] = &mut output[j0..j1];
+                for (scale, right_row) in left_row[k0..k1].iter().zip(right.chunks_exact(n).skip(k0)) {
+                    for (entry, &value) in output.iter_mut().zip(&right_row[j0..j1]) {
+                        *entry = *entry + *scale * value;
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn multiply<F: Num + Copy + Element>(left: &[F], right: &[F], result: &mut [F], (l, m, n): (usize, usize, usize)) {
+    // multiply_into over blocks of rows of the result, each block with the rows of left it needs
+    chunks(result, BLOCK * n, l * m * n, |index, output| {
+        let start: usize = index * BLOCK;
+        let rows: usize = output.len() / n;
+        multiply_into(&left[(start * m)..((start + rows) * m)], right, output, (rows, m, n));
+    });
+}
+
+pub fn mmul<F: Num + Copy + Element, const L: usize, const M: usize, const N: usize>(left: Matrix<F, L, M>, right: Matrix<F, M, N>) -> Matrix<F, L, N> {
+    let mut result: [[F; N]; L] = [[F::zero(); N]; L];
+    if M <= BLOCK && N <= BLOCK {
+        // a single tile, with the sizes known the compiler can unroll these loops
+        for i in 0..L {
+            for k in 0..M {
+                let scale: F = left[i][k];
+                for j in 0..N {
+                    result[i][j] = result[i][j] + scale * right[k][j];
+                }
+            }
+        }
+    } else {
+        multiply(left.as_flattened(), right.as_flattened(), result.as_flattened_mut(), (L, M, N));
+    }
+
+    Matrix::from(result)
+}
+
+fn quadrant<F: Num + Copy>(matrix: &[F], n: usize, row: usize, column: usize) -> Vec<F> {
+    // the h x h block of an n x n matrix starting at (row, column)
+    let h: usize = n / 2;
+    (0..h).flat_map(|i| matrix[((row + i) * n + column)..((row + i) * n + column + h)].iter().copied()).collect()
+}
+
+fn combine<F: Num + Copy>(left: &[F], right: &[F], operation: fn(F, F) -> F) -> Vec<F> {
+    left.iter().zip(right).map(|(&a, &b)| operation(a, b)).collect()
+}
+
+fn winograd<F: Num + Copy + Element>(left: &[F], right: &[F], n: usize, cutoff: usize) -> Vec<F> {
+    if n <= cutoff.max(1) {
+        let mut result: Vec<F> = vec![F::zero(); n * n];
+        multiply(left, right, &mut result, (n, n, n));
+        return result;
+    }
+
+    if n % 2 == 1 {
+        // pad with a row and column of zeros and drop them again afterwards
+        let pad = |matrix: &[F]| -> Vec<F> {
+            let mut padded: Vec<F> = vec![F::zero(); (n + 1) * (n + 1)];
+            for i in 0..n {
+                padded[(i * (n + 1))..(i * (n + 1) + n)].copy_from_slice(&matrix[(i * n)..((i + 1) * n)]);
+            }
+            padded
+        };
+        let product: Vec<F> = winograd(&pad(left), &pad(right), n + 1, cutoff);
+        return (0..n).flat_map(|i| product[(i * (n + 1))..(i * (n + 1) + n)].iter().copied()).collect();
+    }
+
+    let h: usize = n / 2;
+    let (a11, a12, a21, a22) = (quadrant(left, n, 0, 0), quadrant(left, n, 0, h), quadrant(left, n, h, 0), quadrant(left, n, h, h));
+    let (b11, b12, b21, b22) = (quadrant(right, n, 0, 0), quadrant(right, n, 0, h), quadrant(right, n, h, 0), quadrant(right, n, h, h));
+    let add = |a: F, b: F| a + b;
+    let sub = |a: F, b: F| a - b;
+
+    // Winograd's form of Strassen, seven products and fifteen additions
+    let s1: Vec<F> = combine(&a21, &a22, add);
+    let s2: Vec<F> = combine(&s1, &a11, sub);
+    let s3: Vec<F> = combine(&a11, &a21, sub);
+    let s4: Vec<F> = combine(&a12, &s2, sub);
+    let t1: Vec<F> = combine(&b12, &b11, sub);
+    let t2: Vec<F> = combine(&b22, &t1, sub);
+    let t3: Vec<F> = combine(&b22, &b12, sub);
+    let t4: Vec<F> = combine(&t2, &b21, sub);
+
+    let p1: Vec<F> = winograd(&a11, &b11, h, cutoff);
+    let p2: Vec<F> = winograd(&a12, &b21, h, cutoff);
+    let p3: Vec<F> = winograd(&s4, &b22, h, cutoff);
+    let p4: Vec<F> = winograd(&a22, &t4, h, cutoff);
+    let p5: Vec<F> = winograd(&s1, &t1, h, cutoff);
+    let p6: Vec<F> = winograd(&s2, &t2, h, cutoff);
+    let p7: Vec<F> = winograd(&s3, &t3, h, cutoff);
+
+    let u2: Vec<F> = combine(&p1, &p6, add);
+    let u3: Vec<F> = combine(&u2, &p7, add);
+    let u4: Vec<F> = combine(&u2, &p5, add);
+    let c11: Vec<F> = combine(&p1, &p2, add);
+    let c12: Vec<F> = combine(&u4, &p3, add);
+    let c21: Vec<F> = combine(&u3, &p4, sub);
+    let c22: Vec<F> = combine(&u3, &p5, add);
+
+    let mut result: Vec<F> = vec![F::zero(); n * n];
+    for i in 0..h {
+        result[(i * n)..(i * n + h)].copy_from_slice(&c11[(i * h)..((i + 1) * h)]);
+        result[(i * n + h)..((i + 1) * n)].copy_from_slice(&c12[(i * h)..((i + 1) * h)]);
+        result[((i + h) * n)..((i + h) * n + h)].copy_from_slice(&c21[(i * h)..((i + 1) * h)]);
+        result[((i + h) * n + h)..((i + h + 1) * n)].copy_from_slice(&c22[(i * h)..((i + 1) * h)]);
+    }
+
+    result
+}
+
+pub(crate) fn strassen_into<F: Num + Copy + Element>(left: &[F], right: &[F], result: &mut [F], n: usize, cutoff: usize) {
+    result.copy_from_slice(&winograd(left, right, n, cutoff));
+}
+
+pub fn strassen<F: Num + Copy + Element, const N: usize>(left: Matrix<F, N, N>, right: Matrix<F, N, N>, cutoff: usize) -> Matrix<F, N, N> {
+    // O(n^2.81) instead of O(n^3), halving until the blocks are no bigger than cutoff and using
+    // the classical kernel from there. Exact for integers, rationals and finite fields, with
+    // floats the error bound is weaker than mmul's. The differences it forms can go negative,
+    // so unsigned types can overflow where mmul would not
+    let mut result: [[F; N]; N] = [[F::zero(); N]; N];
+    strassen_into(left.as_flattened(), right.as_flattened(), result.as_flattened_mut(), N, cutoff);
+
     Matrix::from(result)
 }
 
@@ -292,7 +510,7 @@ pub fn vmul<F: Num + Copy, const R: usize, const C: usize>(left: Matrix<F, R, C>
     Vector::from(result)
 }
 
-pub fn inverse<F: Num + Copy, const R: usize> (matrix: Matrix<F, R, R>) -> Matrix<F, R, R> {
+pub fn inverse<F: Num + Copy + Element, const R: usize> (matrix: Matrix<F, R, R>) -> Matrix<F, R, R> {
     assert!(matrix.rank() == R);
 
     let mut augmented: Vec<Vec<F>> = Vec::new();
@@ -390,3 +608,345 @@ pub fn identity<F: Num + Copy, const D: usize> () -> Matrix<F, D, D> {
 pub fn zero<F: Num + Copy, const D:usize> () -> Matrix<F, D, D> {
     Matrix::from([[F::zero(); D]; D])
 }
+
+pub fn symmetric_eigen<F: Float, const N: usize> (matrix: Matrix<F, N, N>) -> (Vector<F, N>, Matrix<F, N, N>) {
+    // returns the eigenvalues in ascending order and the matching normalized eigenvectors
+    // as the columns of the second matrix
+    let (values, vectors) = jacobi_eigen(matrix.iter().map(|row| row.to_vec()).collect());
+
+    let mut result_values: [F; N] = [F::zero(); N];
+    let mut result_vectors: [[F; N]; N] = [[F::zero(); N]; N];
+    for i in 0..N {
+        result_values[i] = values[i];
+        result_vectors[i].copy_from_slice(&vectors[i]);
+    }
+
+    (Vector::from(result_values), Matrix::from(result_vectors))
+}
+
+pub(crate) fn nan_last<F: PartialOrd> (a: F, b: F) -> std::cmp::Ordering {
+    // a total order for sorting and pivoting, NaN after every number,
+    // NaN is the only value that does not compare with itself
+    let is_nan = |x: &F| x.partial_cmp(x).is_none();
+    a.partial_cmp(&b).unwrap_or_else(|| is_nan(&a).cmp(&is_nan(&b)))
+}
+
+pub(crate) fn jacobi_eigen<F: Float> (mut a: Vec<Vec<F>>) -> (Vec<F>, Vec<Vec<F>>) {
+    // cyclic Jacobi rotations on a symmetric n x n matrix, eigenvalues ascending and eigenvectors as columns
+    let n: usize = a.len();
+    let mut v: Vec<Vec<F>> = vec![vec![F::zero(); n]; n];
+    for i in 0..n {
+        v[i][i] = F::one();
+    }
+    let two: F = F::one() + F::one();
+
+    let mut scale: F = F::zero();
+    for i in 0..n {
+        for j in 0..n {
+            scale = scale + (a[i][j] * a[i][j]);
+        }
+    }
+
+    for _sweep in 0..100 {
+        let mut off_diagonal: F = F::zero();
+        for p in 0..n {
+            for q in (p + 1)..n {
+                off_diagonal = off_diagonal + (a[p][q] * a[p][q]);
+            }
+        }
+
+        if off_diagonal <= F::epsilon() * F::epsilon() * scale {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q] == F::zero() {
+                    continue;
+                }
+
+                // rotation angle that zeroes a[p][q], taking the smaller root for stability
+                let theta: F = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t: F = F::one().copysign(theta) / (theta.abs() + (theta * theta + F::one()).sqrt());
+                let c: F = F::one() / (t * t + F::one()).sqrt();
+                let s: F = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k][p], a[k][q]);
+                    a[k][p] = (c * akp) - (s * akq);
+                    a[k][q] = (s * akp) + (c * akq);
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = (c * apk) - (s * aqk);
+                    a[q][k] = (s * apk) + (c * aqk);
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k][p], v[k][q]);
+                    v[k][p] = (c * vkp) - (s * vkq);
+                    v[k][q] = (s * vkp) + (c * vkq);
+                }
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| nan_last(a[i][i], a[j][j]));
+
+    let values: Vec<F> = order.iter().map(|&k| a[k][k]).collect();
+    let vectors: Vec<Vec<F>> = (0..n).map(|i| order.iter().map(|&k| v[i][k]).collect()).collect();
+
+    (values, vectors)
+}
+
+pub(crate) fn hessenberg_eigen<F: Float> (mut a: Vec<Vec<F>>) -> Vec<Complex<F>> {
+    // eigenvalues of an n x n matrix sorted by real then imaginary part, the matrix is reduced to
+    // upper Hessenberg form and then to quasi-triangular form by Francis double shift QR steps,
+    // working on the matrix keeps repeated eigenvalues accurate where polynomial roots cannot
+    let n: usize = a.len();
+    let zero: F = F::zero();
+
+    // elimination with pivoting, each step is a similarity transform
+    for m in 1..n.saturating_sub(1) {
+        let mut pivot: usize = m;
+        for i in m..n {
+            if a[i][m - 1].abs() > a[pivot][m - 1].abs() {
+                pivot = i;
+            }
+        }
+        if pivot != m {
+            a.swap(pivot, m);
+            for row in a.iter_mut() {
+                row.swap(pivot, m);
+            }
+        }
+
+        let x: F = a[m][m - 1];
+        if x == zero {
+            continue;
+        }
+        for i in (m + 1)..n {
+            let y: F = a[i][m - 1] / x;
+            if y == zero {
+                continue;
+            }
+            a[i][m - 1] = zero;
+            for j in m..n {
+                a[i][j] = a[i][j] - (y * a[m][j]);
+            }
+            for j in 0..n {
+                a[j][m] = a[j][m] + (y * a[j][i]);
+            }
+        }
+    }
+
+    let mut norm: F = zero;
+    for i in 0..n {
+        for j in i.saturating_sub(1)..n {
+            norm = norm + a[i][j].abs();
+        }
+    }
+
+    // QR steps on the unreduced block ending at row end - 1, splitting off one real eigenvalue
+    // or a pair at a time, a block that has not split after 30 steps is left as NaN
+    let mut result: Vec<Complex<F>> = vec![Complex::new(F::nan(), F::nan()); n];
+    let mut shift: F = zero;
+    let mut steps: usize = 0;
+    let mut end: usize = n;
+    while end > 0 {
+        let last: usize = end - 1;
+        let mut l: usize = last;
+        while l > 0 {
+            let mut s: F = a[l - 1][l - 1].abs() + a[l][l].abs();
+            if s == zero {
+                s = norm;
+            }
+            if a[l][l - 1].abs() + s == s {
+                a[l][l - 1] = zero;
+                break;
+            }
+            l -= 1;
+        }
+
+        let mut x: F = a[last][last];
+        if l == last {
+            result[last] = Complex::new(x + shift, zero);
+            end -= 1;
+            steps = 0;
+            continue;
+        }
+
+        let mut y: F = a[last - 1][last - 1];
+        let mut w: F = a[last][last - 1] * a[last - 1][last];
+        if l == last - 1 {
+            // the 2 x 2 block at the bottom has a real pair or a complex conjugate pair
+            let p: F = (y - x) / (F::one() + F::one());
+            let q: F = (p * p) + w;
+            let z: F = q.abs().sqrt();
+            x = x + shift;
+            if q >= zero {
+                let z: F = p + z.copysign(p);
+                result[last - 1] = Complex::new(x + z, zero);
+                result[last] = Complex::new(if z != zero { x - (w / z) } else { x + z }, zero);
+            } else {
+                result[last - 1] = Complex::new(x + p, -z);
+                result[last] = Complex::new(x + p, z);
+            }
+            end -= 2;
+            steps = 0;
+            continue;
+        }
+
+        if steps == 30 {
+            break;
+        }
+        if steps == 10 || steps == 20 {
+            // an exceptional shift for blocks that are slow to split
+            shift = shift + x;
+            for i in 0..end {
+                a[i][i] = a[i][i] - x;
+            }
+            let s: F = a[last][last - 1].abs() + a[last - 1][last - 2].abs();
+            x = F::from(0.75).unwrap() * s;
+            y = x;
+            w = F::from(-0.4375).unwrap() * s * s;
+        }
+        steps += 1;
+
+        // start the step where two consecutive subdiagonal entries are small
+        let mut m: usize = last - 2;
+        let (mut p, mut q, mut r): (F, F, F);
+        loop {
+            let z: F = a[m][m];
+            let (u, v): (F, F) = (x - z, y - z);
+            p = (((u * v) - w) / a[m + 1][m]) + a[m][m + 1];
+            q = a[m + 1][m + 1] - z - u - v;
+            r = a[m + 2][m + 1];
+            let s: F = p.abs() + q.abs() + r.abs();
+            (p, q, r) = (p / s, q / s, r / s);
+            if m == l {
+                break;
+            }
+            let u: F = a[m][m - 1].abs() * (q.abs() + r.abs());
+            let v: F = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+            if u + v == v {
+                break;
+            }
+            m -= 1;
+        }
+        for i in (m + 2)..end {
+            a[i][i - 2] = zero;
+            if i != m + 2 {
+                a[i][i - 3] = zero;
+            }
+        }
+
+        // chase the bulge down with Householder reflections of size 3
+        for k in m..last {
+            if k != m {
+                p = a[k][k - 1];
+                q = a[k + 1][k - 1];
+                r = if k != last - 1 { a[k + 2][k - 1] } else { zero };
+                x = p.abs() + q.abs() + r.abs();
+                if x != zero {
+                    (p, q, r) = (p / x, q / x, r / x);
+                }
+            }
+            let s: F = ((p * p) + (q * q) + (r * r)).sqrt().copysign(p);
+            if s == zero {
+                continue;
+            }
+            if k == m {
+                if l != m {
+                    a[k][k - 1] = zero - a[k][k - 1];
+                }
+            } else {
+                a[k][k - 1] = zero - (s * x);
+            }
+            p = p + s;
+            x = p / s;
+            y = q / s;
+            let z: F = r / s;
+            q = q / p;
+            r = r / p;
+            for j in k..end {
+                let mut p: F = a[k][j] + (q * a[k + 1][j]);
+                if k != last - 1 {
+                    p = p + (r * a[k + 2][j]);
+                    a[k + 2][j] = a[k + 2][j] - (p * z);
+                }
+                a[k + 1][j] = a[k + 1][j] - (p * y);
+                a[k][j] = a[k][j] - (p * x);
+            }
+            for i in l..=last.min(k + 3) {
+                let mut p: F = (x * a[i][k]) + (y * a[i][k + 1]);
+                if k != last - 1 {
+                    p = p + (z * a[i][k + 2]);
+                    a[i][k + 2] = a[i][k + 2] - (p * r);
+                }
+                a[i][k + 1] = a[i][k + 1] - (p * q);
+                a[i][k] = a[i][k] - p;
+            }
+        }
+    }
+
+    result.sort_by(|a, b| nan_last(a.re, b.re).then(nan_last(a.im, b.im)));
+    result
+}
+
+pub fn vandermonde<F: Num + Copy, const R: usize, const C: usize> (nodes: Vector<F, R>) -> Matrix<F, R, C> {
+    // row i is [1, x_i, x_i^2, ..., x_i^(C - 1)]
+    let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+    for i in 0..R {
+        let mut power: F = F::one();
+        for j in 0..C {
+            result[i][j] = power;
+            power = power * nodes[i];
+        }
+    }
+
+    Matrix::from(result)
+}
+
+pub(crate) fn solve_rows<F: Num + Copy + PartialOrd> (mut system: Vec<Vec<F>>) -> Option<Vec<F>> {
+    // solves an n x (n + 1) augmented system, or returns None if it is singular
+    let n: usize = system.len();
+    let magnitude = |x: F| -> F {
+        if x < F::zero() { F::zero() - x } else { x }
+    };
+
+    for col in 0..n {
+        // partial pivoting keeps floating point error down, and costs nothing for exact types
+        let mut pivot_row: usize = col;
+        for i in (col + 1)..n {
+            if magnitude(system[i][col]) > magnitude(system[pivot_row][col]) {
+                pivot_row = i;
+            }
+        }
+
+        if system[pivot_row][col] == F::zero() {
+            return None;
+        }
+
+        system.swap(col, pivot_row);
+
+        for i in (col + 1)..n {
+            let scale: F = system[i][col] / system[col][col];
+            for j in col..(n + 1) {
+                system[i][j] = system[i][j] - (scale * system[col][j]);
+            }
+        }
+    }
+
+    // back substitution
+    let mut solution: Vec<F> = vec![F::zero(); n];
+    for i in (0..n).rev() {
+        let mut sum: F = system[i][n];
+        for j in (i + 1)..n {
+            sum = sum - (system[i][j] * solution[j]);
+        }
+        solution[i] = sum / system[i][i];
+    }
+
+    Some(solution)
+}
@@ -0,0 +1,263 @@
+use std::fmt;
+use std::error::Error;
+
+use num::Num;
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NpyError {
+    Format(String), // not an npy file, or one this reader does not understand
+    Dtype { expected: String, found: String }, // the element type has to match exactly, no conversions
+    ShapeMismatch { expected: Vec<usize>, found: Vec<usize> }
+}
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NpyError::Format(message) => write!(f, "invalid npy data: {}", message),
+            NpyError::Dtype { expected, found } => write!(f, "expected dtype {}, found {}", expected, found),
+            NpyError::ShapeMismatch { expected, found } => write!(f, "expected shape {:?}, found {:?}", expected, found)
+        }
+    }
+}
+impl Error for NpyError {}
+
+pub trait NpyElement: Copy {
+    const KIND: char; // 'f', 'i' or 'u' as in the numpy type string
+    const SIZE: usize;
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+    fn to_le_bytes(self) -> Vec<u8>;
+}
+impl NpyElement for f32 {
+    const KIND: char = 'f';
+    const SIZE: usize = 4;
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        let bytes: [u8; 4] = bytes.try_into().unwrap();
+        if big_endian { f32::from_be_bytes(bytes) } else { f32::from_le_bytes(bytes) }
+    }
+    fn to_le_bytes(self) -> Vec<u8> {
+        f32::to_le_bytes(self).to_vec()
+    }
+}
+impl NpyElement for f64 {
+    const KIND: char = 'f';
+    const SIZE: usize = 8;
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        let bytes: [u8; 8] = bytes.try_into().unwrap();
+        if big_endian { f64::from_be_bytes(bytes) } else { f64::from_le_bytes(bytes) }
+    }
+    fn to_le_bytes(self) -> Vec<u8> {
+        f64::to_le_bytes(self).to_vec()
+    }
+}
+impl NpyElement for i32 {
+    const KIND: char = 'i';
+    const SIZE: usize = 4;
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        let bytes: [u8; 4] = bytes.try_into().unwrap();
+        if big_endian { i32::from_be_bytes(bytes) } else { i32::from_le_bytes(bytes) }
+    }
+    fn to_le_bytes(self) -> Vec<u8> {
+        i32::to_le_bytes(self).to_vec()
+    }
+}
+impl NpyElement for i64 {
+    const KIND: char = 'i';
+    const SIZE: usize = 8;
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        let bytes: [u8; 8] = bytes.try_into().unwrap();
+        if big_endian { i64::from_be_bytes(bytes) } else { i64::from_le_bytes(bytes) }
+    }
+    fn to_le_bytes(self) -> Vec<u8> {
+        i64::to_le_bytes(self).to_vec()
+    }
+}
+impl NpyElement for u8 {
+    const KIND: char = 'u';
+    const SIZE: usize = 1;
+    fn from_bytes(bytes: &[u8], _big_endian: bool) -> Self {
+        bytes[0]
+    }
+    fn to_le_bytes(self) -> Vec<u8> {
+        vec![self]
+    }
+}
+
+fn format_error(message: &str) -> NpyError {
+    NpyError::Format(String::from(message))
+}
+
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    // the text after 'key': in the python dict literal numpy writes
+    let quoted: String = format!("'{}'", key);
+    let start: usize = header.find(&quoted).ok_or_else(|| NpyError::Format(format!("header has no {}", quoted)))?;
+    let rest: &str = header[start + quoted.len()..].trim_start();
+    rest.strip_prefix(':').map(|value| value.trim_start()).ok_or_else(|| NpyError::Format(format!("expected ':' after {}", quoted)))
+}
+
+fn read_npy<F: NpyElement>(bytes: &[u8]) -> Result<(Vec<usize>, bool, Vec<F>), NpyError> {
+    // the shape, whether the data is in fortran order, and the elements as stored
+    if bytes.len() < 10 || &bytes[..6] != MAGIC {
+        return Err(format_error("missing \\x93NUMPY magic"));
+    }
+
+    let (header_length, header_start): (usize, usize) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 if bytes.len() >= 12 => (u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]) as usize, 12),
+        major => return Err(NpyError::Format(format!("unsupported version {}.{}", major, bytes[7])))
+    };
+    let data_start: usize = header_start + header_length;
+    if bytes.len() < data_start {
+        return Err(format_error("header is cut short"));
+    }
+    let header: &str = std::str::from_utf8(&bytes[header_start..data_start]).map_err(|_| format_error("header is not text"))?;
+
+    // descr is a quoted type string such as '<f8', either byte order is accepted
+    let descr: &str = header_value(header, "descr")?;
+    let descr: &str = descr.strip_prefix('\'').and_then(|d| d.split('\'').next()).ok_or_else(|| format_error("descr is not a string"))?;
+    let element: String = format!("{}{}", F::KIND, F::SIZE);
+    let expected: String = format!("{}{}", if F::SIZE == 1 { '|' } else { '<' }, element);
+    let mut characters = descr.chars();
+    let big_endian: bool = match characters.next() {
+        Some('>') => true,
+        Some('<') | Some('|') => false,
+        Some('=') => cfg!(target_endian = "big"),
+        _ => return Err(NpyError::Dtype { expected, found: String::from(descr) })
+    };
+    if characters.as_str() != element {
+        return Err(NpyError::Dtype { expected, found: String::from(descr) });
+    }
+
+    let fortran_order: bool = match header_value(header, "fortran_order")? {
+        value if value.starts_with("True") => true,
+        value if value.starts_with("False") => false,
+        _ => return Err(format_error("fortran_order is not True or False"))
+    };
+
+    let shape: &str = header_value(header, "shape")?;
+    let shape: &str = shape.strip_prefix('(').and_then(|s| s.split(')').next()).ok_or_else(|| format_error("shape is not a tuple"))?;
+    let mut dimensions: Vec<usize> = Vec::new();
+    for dimension in shape.split(',').map(|d| d.trim()).filter(|d| !d.is_empty()) {
+        dimensions.push(dimension.parse().map_err(|_| NpyError::Format(format!("bad dimension '{}' in shape", dimension)))?);
+    }
+
+    // a shape whose size overflows cannot match any data, so it is refused before counting bytes
+    let too_large = || NpyError::Format(format!("shape ({}) is too large", shape));
+    let count: usize = dimensions.iter().try_fold(1usize, |count, &d| count.checked_mul(d)).ok_or_else(too_large)?;
+    let length: usize = count.checked_mul(F::SIZE).ok_or_else(too_large)?;
+    let data: &[u8] = &bytes[data_start..];
+    if data.len() < length {
+        return Err(NpyError::Format(format!("expected {} bytes of data, found {}", length, data.len())));
+    }
+    let elements: Vec<F> = data.chunks_exact(F::SIZE).take(count).map(|chunk| F::from_bytes(chunk, big_endian)).collect();
+
+    Ok((dimensions, fortran_order, elements))
+}
+
+fn write_npy<F: NpyElement>(shape: &[usize], elements: impl Iterator<Item = F>) -> Vec<u8> {
+    // little endian, C order, version 1 unless the header is too long for it
+    let endian: char = if F::SIZE == 1 { '|' } else { '<' };
+    let dimensions: Vec<String> = shape.iter().map(|d| d.to_string()).collect();
+    let shape: String = if dimensions.len() == 1 { format!("({},)", dimensions[0]) } else { format!("({})", dimensions.join(", ")) };
+    let mut header: String = format!("{{'descr': '{}{}{}', 'fortran_order': False, 'shape': {}, }}", endian, F::KIND, F::SIZE, shape);
+
+    // pad with spaces and a newline so the data starts on a 64 byte boundary
+    let version: u8 = if header.len() + 64 <= usize::from(u16::MAX) { 1 } else { 2 };
+    let preamble: usize = if version == 1 { 10 } else { 12 };
+    while !(preamble + header.len() + 1).is_multiple_of(64) {
+        header.push(' ');
+    }
+    header.push('\n');
+
+    let mut result: Vec<u8> = MAGIC.to_vec();
+    result.extend([version, 0]);
+    match version {
+        1 => result.extend((header.len() as u16).to_le_bytes()),
+        _ => result.extend((header.len() as u32).to_le_bytes())
+    }
+    result.extend(header.as_bytes());
+    for element in elements {
+        result.extend(element.to_le_bytes());
+    }
+
+    result
+}
+
+impl<F: Num + Copy + NpyElement, const R: usize, const C: usize> Matrix<F, R, C> {
+    pub fn from_npy(bytes: &[u8]) -> Result<Matrix<F, R, C>, NpyError> {
+        let (shape, fortran_order, elements) = read_npy::<F>(bytes)?;
+        if shape != [R, C] {
+            return Err(NpyError::ShapeMismatch { expected: vec![R, C], found: shape });
+        }
+
+        let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+        for (k, element) in elements.into_iter().enumerate() {
+            match fortran_order {
+                true => result[k % R][k / R] = element,
+                false => result[k / C][k % C] = element
+            }
+        }
+
+        Ok(Matrix::from(result))
+    }
+
+    pub fn to_npy(&self) -> Vec<u8> {
+        write_npy(&[R, C], self.iter().flatten().copied())
+    }
+}
+
+impl<F: Num + Copy + NpyElement, const R: usize> Vector<F, R> {
+    pub fn from_npy(bytes: &[u8]) -> Result<Vector<F, R>, NpyError> {
+        // one dimensional arrays only, reshape column vectors on the python side
+        let (shape, _, elements) = read_npy::<F>(bytes)?;
+        if shape != [R] {
+            return Err(NpyError::ShapeMismatch { expected: vec![R], found: shape });
+        }
+
+        let mut result: [F; R] = [F::zero(); R];
+        result.copy_from_slice(&elements);
+        Ok(Vector::from(result))
+    }
+
+    pub fn to_npy(&self) -> Vec<u8> {
+        write_npy(&[R], self.iter().copied())
+    }
+}
+
+#[cfg(feature = "npz")]
+pub fn read_npz(bytes: &[u8]) -> Result<std::collections::BTreeMap<String, Vec<u8>>, NpyError> {
+    // the npy data of every array in the archive, by name without the .npy extension
+    use std::io::Read;
+
+    let archive_error = |error: zip::result::ZipError| NpyError::Format(format!("npz archive: {}", error));
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes)).map_err(archive_error)?;
+    let mut result: std::collections::BTreeMap<String, Vec<u8>> = std::collections::BTreeMap::new();
+    for i in 0..archive.len() {
+        let mut file = archive.by_index(i).map_err(archive_error)?;
+        let name: String = file.name().strip_suffix(".npy").unwrap_or(file.name()).to_string();
+        let mut data: Vec<u8> = Vec::new();
+        file.read_to_end(&mut data).map_err(|error| NpyError::Format(format!("npz archive: {}", error)))?;
+        result.insert(name, data);
+    }
+
+    Ok(result)
+}
+
+#[cfg(feature = "npz")]
+pub fn write_npz(arrays: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    // deflated like numpy.savez_compressed, the arrays come from to_npy
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+
+    let options: SimpleFileOptions = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    for (name, data) in arrays {
+        writer.start_file(format!("{}.npy", name), options).expect("writing to memory cannot fail");
+        writer.write_all(data).expect("writing to memory cannot fail");
+    }
+
+    writer.finish().expect("writing to memory cannot fail").into_inner()
+}
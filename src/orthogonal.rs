@@ -0,0 +1,303 @@
+use num::{Float, Num, NumCast};
+
+use crate::polynomial::{Polynomial, padd, psub, pscale, pmul, compose};
+use crate::matrix::nan_last;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quadrature<F: Float> {
+	pub nodes: Vec<F>,
+	pub weights: Vec<F>
+}
+impl<F: Float> Quadrature<F> {
+	pub fn integrate<G: Fn(F) -> F>(&self, f: G) -> F {
+		let mut sum: F = F::zero();
+		for i in 0..self.nodes.len() {
+			sum = sum + (self.weights[i] * f(self.nodes[i]));
+		}
+
+		sum
+	}
+}
+
+fn x_times<F: Num + Copy>(polynomial: &Polynomial<F>) -> Polynomial<F> {
+	pmul(Polynomial::from(vec![F::zero(), F::one()]), polynomial.clone())
+}
+
+fn cast<F: NumCast>(n: usize) -> F {
+	NumCast::from(n).unwrap()
+}
+
+fn pi<F: Float>() -> F {
+	NumCast::from(std::f64::consts::PI).unwrap()
+}
+
+pub fn chebyshev<F: Num + Copy>(n: usize) -> Polynomial<F> {
+	// first kind, T_{k+1} = 2x T_k - T_{k-1}
+	let two: F = F::one() + F::one();
+	let mut previous: Polynomial<F> = Polynomial::from(vec![F::one()]);
+	let mut current: Polynomial<F> = Polynomial::from(vec![F::zero(), F::one()]);
+	if n == 0 {
+		return previous;
+	}
+
+	for _ in 1..n {
+		let next: Polynomial<F> = psub(pscale(two, x_times(&current)), previous);
+		previous = current;
+		current = next;
+	}
+
+	current
+}
+
+pub fn chebyshev_second<F: Num + Copy>(n: usize) -> Polynomial<F> {
+	// second kind, U_{k+1} = 2x U_k - U_{k-1}
+	let two: F = F::one() + F::one();
+	let mut previous: Polynomial<F> = Polynomial::from(vec![F::one()]);
+	let mut current: Polynomial<F> = Polynomial::from(vec![F::zero(), two]);
+	if n == 0 {
+		return previous;
+	}
+
+	for _ in 1..n {
+		let next: Polynomial<F> = psub(pscale(two, x_times(&current)), previous);
+		previous = current;
+		current = next;
+	}
+
+	current
+}
+
+pub fn legendre<F: Num + Copy + NumCast>(n: usize) -> Polynomial<F> {
+	// (k + 1) P_{k+1} = (2k + 1) x P_k - k P_{k-1}, so integer types will truncate
+	let mut previous: Polynomial<F> = Polynomial::from(vec![F::one()]);
+	let mut current: Polynomial<F> = Polynomial::from(vec![F::zero(), F::one()]);
+	if n == 0 {
+		return previous;
+	}
+
+	for k in 1..n {
+		let next: Polynomial<F> = psub(pscale(cast(2 * k + 1), x_times(&current)), pscale(cast(k), previous)) / cast(k + 1);
+		previous = current;
+		current = next;
+	}
+
+	current
+}
+
+pub fn hermite<F: Num + Copy + NumCast>(n: usize) -> Polynomial<F> {
+	// physicists' convention, H_{k+1} = 2x H_k - 2k H_{k-1}
+	let mut previous: Polynomial<F> = Polynomial::from(vec![F::one()]);
+	let mut current: Polynomial<F> = Polynomial::from(vec![F::zero(), cast(2)]);
+	if n == 0 {
+		return previous;
+	}
+
+	for k in 1..n {
+		let next: Polynomial<F> = psub(pscale(cast(2), x_times(&current)), pscale(cast(2 * k), previous));
+		previous = current;
+		current = next;
+	}
+
+	current
+}
+
+pub fn laguerre<F: Num + Copy + NumCast>(n: usize) -> Polynomial<F> {
+	// (k + 1) L_{k+1} = (2k + 1 - x) L_k - k L_{k-1}
+	let mut previous: Polynomial<F> = Polynomial::from(vec![F::one()]);
+	let mut current: Polynomial<F> = Polynomial::from(vec![F::one(), F::zero() - F::one()]);
+	if n == 0 {
+		return previous;
+	}
+
+	for k in 1..n {
+		let factor: Polynomial<F> = Polynomial::from(vec![cast(2 * k + 1), F::zero() - F::one()]);
+		let next: Polynomial<F> = psub(pmul(factor, current.clone()), pscale(cast(k), previous)) / cast(k + 1);
+		previous = current;
+		current = next;
+	}
+
+	current
+}
+
+pub fn tridiagonal_eigen<F: Float>(diagonal: &[F], off_diagonal: &[F]) -> (Vec<F>, Vec<Vec<F>>) {
+	// implicit QL with Wilkinson shifts for a symmetric tridiagonal matrix,
+	// returns the eigenvalues in ascending order and the matching normalized eigenvectors
+	let n: usize = diagonal.len();
+	assert_eq!(off_diagonal.len() + 1, n.max(1));
+
+	// NaN or infinite entries would never converge, every eigenpair is NaN instead
+	if diagonal.iter().chain(off_diagonal).any(|x| !x.is_finite()) {
+		return (vec![F::nan(); n], vec![vec![F::nan(); n]; n]);
+	}
+
+	let two: F = cast(2);
+	let mut d: Vec<F> = diagonal.to_vec();
+	let mut e: Vec<F> = off_diagonal.to_vec();
+	e.push(F::zero());
+
+	// z accumulates the rotations, column k ends up as the eigenvector for d[k]
+	let mut z: Vec<Vec<F>> = vec![vec![F::zero(); n]; n];
+	for i in 0..n {
+		z[i][i] = F::one();
+	}
+
+	for l in 0..n {
+		let mut iterations: usize = 0;
+		loop {
+			// look for a negligible off diagonal element to split the matrix at
+			let mut m: usize = l;
+			while m + 1 < n {
+				let scale: F = d[m].abs() + d[m + 1].abs();
+				if e[m].abs() <= F::epsilon() * scale {
+					break;
+				}
+				m += 1;
+			}
+
+			if m == l {
+				break;
+			}
+
+			iterations += 1;
+			assert!(iterations < 64, "tridiagonal eigenvalue iteration did not converge");
+
+			let mut g: F = (d[l + 1] - d[l]) / (two * e[l]);
+			let mut r: F = g.hypot(F::one());
+			g = d[m] - d[l] + (e[l] / (g + r.copysign(g)));
+
+			let mut s: F = F::one();
+			let mut c: F = F::one();
+			let mut p: F = F::zero();
+			let mut underflow: bool = false;
+			for i in (l..m).rev() {
+				let f: F = s * e[i];
+				let b: F = c * e[i];
+				r = f.hypot(g);
+				e[i + 1] = r;
+				if r == F::zero() {
+					// recover from underflow by deflating and starting over
+					d[i + 1] = d[i + 1] - p;
+					e[m] = F::zero();
+					underflow = true;
+					break;
+				}
+
+				s = f / r;
+				c = g / r;
+				g = d[i + 1] - p;
+				r = ((d[i] - g) * s) + (two * c * b);
+				p = s * r;
+				d[i + 1] = g + p;
+				g = (c * r) - b;
+
+				for k in 0..n {
+					let t: F = z[k][i + 1];
+					z[k][i + 1] = (s * z[k][i]) + (c * t);
+					z[k][i] = (c * z[k][i]) - (s * t);
+				}
+			}
+
+			if underflow {
+				continue;
+			}
+
+			d[l] = d[l] - p;
+			e[l] = g;
+			e[m] = F::zero();
+		}
+	}
+
+	let mut order: Vec<usize> = (0..n).collect();
+	order.sort_by(|&i, &j| nan_last(d[i], d[j]));
+
+	let eigenvalues: Vec<F> = order.iter().map(|&k| d[k]).collect();
+	let eigenvectors: Vec<Vec<F>> = order.iter().map(|&k| (0..n).map(|i| z[i][k]).collect()).collect();
+
+	(eigenvalues, eigenvectors)
+}
+
+fn golub_welsch<F: Float>(diagonal: &[F], off_diagonal: &[F], moment: F) -> Quadrature<F> {
+	// the nodes are the eigenvalues of the Jacobi matrix of the three term recurrence,
+	// the weights come from the first component of each eigenvector
+	let (nodes, eigenvectors) = tridiagonal_eigen(diagonal, off_diagonal);
+	let weights: Vec<F> = eigenvectors.iter().map(|v| moment * v[0] * v[0]).collect();
+
+	Quadrature { nodes, weights }
+}
+
+pub fn gauss_legendre<F: Float>(n: usize) -> Quadrature<F> {
+	// weight 1 on [-1, 1]
+	let diagonal: Vec<F> = vec![F::zero(); n];
+	let off_diagonal: Vec<F> = (1..n).map(|k| {
+		let k: F = cast(k);
+		k / ((cast::<F>(4) * k * k) - F::one()).sqrt()
+	}).collect();
+
+	golub_welsch(&diagonal, &off_diagonal, cast(2))
+}
+
+pub fn gauss_chebyshev<F: Float>(n: usize) -> Quadrature<F> {
+	// weight 1 / sqrt(1 - x^2) on [-1, 1]
+	let diagonal: Vec<F> = vec![F::zero(); n];
+	let off_diagonal: Vec<F> = (1..n).map(|k| {
+		if k == 1 { F::one() / cast::<F>(2).sqrt() } else { F::one() / cast(2) }
+	}).collect();
+
+	golub_welsch(&diagonal, &off_diagonal, pi())
+}
+
+pub fn gauss_hermite<F: Float>(n: usize) -> Quadrature<F> {
+	// weight e^{-x^2} on the real line
+	let diagonal: Vec<F> = vec![F::zero(); n];
+	let off_diagonal: Vec<F> = (1..n).map(|k| (cast::<F>(k) / cast(2)).sqrt()).collect();
+
+	golub_welsch(&diagonal, &off_diagonal, pi::<F>().sqrt())
+}
+
+pub fn gauss_laguerre<F: Float>(n: usize) -> Quadrature<F> {
+	// weight e^{-x} on [0, infinity)
+	let diagonal: Vec<F> = (0..n).map(|k| cast(2 * k + 1)).collect();
+	let off_diagonal: Vec<F> = (1..n).map(cast).collect();
+
+	golub_welsch(&diagonal, &off_diagonal, F::one())
+}
+
+pub fn chebyshev_coefficients<F: Float, G: Fn(F) -> F>(f: G, degree: usize, a: F, b: F) -> Vec<F> {
+	// c_j such that f(x) ~ c_0 / 2 + sum c_j T_j(t) with t = (2x - a - b) / (b - a),
+	// sampled at the Chebyshev nodes of the first kind
+	let count: usize = degree + 1;
+	let pi: F = pi();
+	let half: F = F::one() / cast(2);
+
+	let mut samples: Vec<F> = Vec::new();
+	for k in 0..count {
+		let t: F = (pi * (cast::<F>(k) + half) / cast(count)).cos();
+		samples.push(f((half * (b - a) * t) + (half * (b + a))));
+	}
+
+	let mut coefficients: Vec<F> = Vec::new();
+	for j in 0..count {
+		let mut sum: F = F::zero();
+		for k in 0..count {
+			sum = sum + (samples[k] * (pi * cast::<F>(j) * (cast::<F>(k) + half) / cast(count)).cos());
+		}
+		coefficients.push(sum * cast(2) / cast(count));
+	}
+
+	coefficients
+}
+
+pub fn chebyshev_approximation<F: Float, G: Fn(F) -> F>(f: G, degree: usize, a: F, b: F) -> Polynomial<F> {
+	// the Chebyshev series of f on [a, b], expanded into the monomial basis
+	let coefficients: Vec<F> = chebyshev_coefficients(f, degree, a, b);
+
+	let mut series: Polynomial<F> = Polynomial::from(vec![coefficients[0] / cast(2)]);
+	for j in 1..coefficients.len() {
+		series = padd(series, pscale(coefficients[j], chebyshev(j)));
+	}
+
+	// substitute t = (2x - a - b) / (b - a)
+	let width: F = b - a;
+	let substitution: Polynomial<F> = Polynomial::from(vec![(F::zero() - (a + b)) / width, cast::<F>(2) / width]);
+	compose(series, substitution)
+}
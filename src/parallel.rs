@@ -0,0 +1,40 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// With the rayon feature the expensive operations split their rows across threads once the work
+// is above a threshold, below it spawning tasks costs more than it saves. Each entry goes through
+// the same operations in the same order either way, so results do not depend on the thread count.
+
+// Send + Sync whether or not the rayon feature is on, so turning it on never breaks a build,
+// every scalar type in the crate already is
+pub trait Element: Send + Sync {}
+impl<T: Send + Sync> Element for T {}
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(1 << 16);
+
+pub fn threshold() -> usize {
+    // in multiply-adds, the product of an l x m and an m x n matrix counts l * m * n
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+pub fn set_threshold(work: usize) {
+    // 0 runs everything in parallel, usize::MAX nothing
+    THRESHOLD.store(work, Ordering::Relaxed);
+}
+
+pub(crate) fn chunks<F: Element>(data: &mut [F], size: usize, work: usize, operation: impl Fn(usize, &mut [F]) + Element) {
+    // operation(index, chunk) on every size long chunk of data
+    if data.is_empty() {
+        return;
+    }
+
+    #[cfg(feature = "rayon")]
+    if work >= threshold() {
+        use rayon::prelude::*;
+        data.par_chunks_mut(size).enumerate().for_each(|(index, chunk)| operation(index, chunk));
+        return;
+    }
+
+    #[cfg(not(feature = "rayon"))]
+    let _ = work;
+    data.chunks_mut(size).enumerate().for_each(|(index, chunk)| operation(index, chunk));
+}
@@ -0,0 +1,361 @@
+use std::fmt;
+use std::error::Error;
+use std::ops::Range;
+use std::str::FromStr;
+
+use num::{Num, Bounded};
+
+use crate::vector::Vector;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::dynamic::DynMatrix;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub span: Range<usize>, // byte offsets into the input
+    pub message: String
+}
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}..{}: {}", self.span.start, self.span.end, self.message)
+    }
+}
+impl Error for ParseError {}
+
+pub const MAX_DEGREE: usize = 1 << 16; // the highest power a parsed polynomial may have
+
+pub type Row<F> = (Range<usize>, Vec<F>); // the entries of one row and where it sits in the input
+
+fn error<T>(span: Range<usize>, message: String) -> Result<T, ParseError> {
+    Err(ParseError { span, message })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    Comma,
+    Semicolon,
+    Newline,
+    Number(&'a str)
+}
+
+fn lex(text: &str) -> Vec<(Range<usize>, Token<'_>)> {
+    // anything that is not a bracket, separator or whitespace is part of a number
+    let mut result: Vec<(Range<usize>, Token)> = Vec::new();
+    let mut number: Option<usize> = None;
+    for (index, c) in text.char_indices() {
+        let token: Option<Token> = match c {
+            '[' => Some(Token::Open),
+            ']' => Some(Token::Close),
+            ',' => Some(Token::Comma),
+            ';' => Some(Token::Semicolon),
+            '\n' => Some(Token::Newline),
+            _ => None
+        };
+
+        let boundary: bool = token.is_some() || c.is_whitespace();
+        match (number, boundary) {
+            (None, false) => number = Some(index),
+            (Some(start), true) => {
+                result.push((start..index, Token::Number(&text[start..index])));
+                number = None;
+            },
+            _ => ()
+        }
+        if let Some(token) = token {
+            result.push((index..(index + 1), token));
+        }
+    }
+    if let Some(start) = number {
+        result.push((start..text.len(), Token::Number(&text[start..])));
+    }
+
+    result
+}
+
+fn number<F: FromStr>(token: &str, span: Range<usize>) -> Result<F, ParseError> {
+    token.parse().or_else(|_| error(span, format!("invalid number '{}'", token)))
+}
+
+pub fn parse_rows<F: FromStr>(text: &str) -> Result<Vec<Row<F>>, ParseError> {
+    // "[1 2; 3 4]" with rows split by ; or newlines, or "[[1, 2], [3, 4]]",
+    // each row comes with its span so callers can point at the one with the wrong length
+    let tokens: Vec<(Range<usize>, Token)> = lex(text);
+    let end: Range<usize> = text.len()..text.len();
+    let mut position: usize = tokens.iter().take_while(|(_, token)| *token == Token::Newline).count();
+
+    match tokens.get(position) {
+        Some((_, Token::Open)) => position += 1,
+        Some((span, _)) => return error(span.clone(), String::from("expected '['")),
+        None => return error(end, String::from("expected '['"))
+    }
+
+    let nested: bool = tokens[position..].iter().find(|(_, token)| *token != Token::Newline).map(|(_, token)| *token) == Some(Token::Open);
+    let mut rows: Vec<Row<F>> = Vec::new();
+    let mut row: Vec<F> = Vec::new();
+    let mut row_start: Option<usize> = None;
+    let mut row_end: usize = 0;
+    let mut expect_value: bool = true; // in nested rows commas have to alternate with numbers
+    let mut inside: bool = !nested; // in a nested list, whether a row is open
+
+    loop {
+        let (span, token) = match tokens.get(position) {
+            Some((span, token)) => (span.clone(), *token),
+            None => return error(end, String::from("missing ']'"))
+        };
+        position += 1;
+
+        match (nested, inside, token) {
+            (_, _, Token::Newline) if nested => (),
+            (_, true, Token::Number(value)) => {
+                if nested && !expect_value {
+                    return error(span, String::from("expected ',' between entries"));
+                }
+                row.push(number(value, span.clone())?);
+                row_start = row_start.or(Some(span.start));
+                row_end = span.end;
+                expect_value = false;
+            },
+            (true, true, Token::Comma) if !expect_value => expect_value = true,
+            (false, true, Token::Comma) if !row.is_empty() && !expect_value => expect_value = true,
+            (false, true, Token::Semicolon | Token::Newline) => {
+                if expect_value && !row.is_empty() {
+                    return error(span, String::from("expected a number after ','"));
+                }
+                if !row.is_empty() {
+                    rows.push((row_start.unwrap()..row_end, std::mem::take(&mut row)));
+                } else if token == Token::Semicolon {
+                    return error(span, String::from("empty row"));
+                }
+                row_start = None;
+                expect_value = true;
+            },
+            (false, true, Token::Close) => {
+                if expect_value && !row.is_empty() {
+                    return error(span, String::from("expected a number after ','"));
+                }
+                if !row.is_empty() {
+                    rows.push((row_start.unwrap()..row_end, std::mem::take(&mut row)));
+                }
+                break;
+            },
+            (true, false, Token::Open) if expect_value => {
+                inside = true;
+                row_start = Some(span.start);
+            },
+            (true, true, Token::Close) => {
+                if expect_value && !row.is_empty() {
+                    return error(span, String::from("expected a number after ','"));
+                }
+                rows.push((row_start.unwrap()..span.end, std::mem::take(&mut row)));
+                inside = false;
+                expect_value = false;
+            },
+            (true, false, Token::Comma) if !expect_value => expect_value = true,
+            (true, false, Token::Close) => {
+                if expect_value && !rows.is_empty() {
+                    return error(span, String::from("expected a row after ','"));
+                }
+                break;
+            },
+            (_, _, Token::Open) => return error(span, String::from("unexpected '['")),
+            (_, _, Token::Close) => return error(span, String::from("unexpected ']'")),
+            (_, _, Token::Comma) => return error(span, String::from("unexpected ','")),
+            (_, _, Token::Semicolon) => return error(span, String::from("unexpected ';'")),
+            (_, _, Token::Number(_)) => return error(span, String::from("expected '[' before a row")),
+            (_, _, Token::Newline) => ()
+        }
+    }
+
+    if let Some((span, _)) = tokens[position..].iter().find(|(_, token)| *token != Token::Newline) {
+        return error(span.start..text.len(), String::from("unexpected text after ']'"));
+    }
+
+    Ok(rows)
+}
+
+impl<F: Num + Copy + FromStr, const R: usize, const C: usize> FromStr for Matrix<F, R, C> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<Row<F>> = parse_rows(text)?;
+        if rows.len() != R {
+            return error(0..text.len(), format!("expected {} rows, found {}", R, rows.len()));
+        }
+
+        let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+        for (i, (span, row)) in rows.into_iter().enumerate() {
+            if row.len() != C {
+                return error(span, format!("expected {} entries in row {}, found {}", C, i + 1, row.len()));
+            }
+            result[i].copy_from_slice(&row);
+        }
+
+        Ok(Matrix::from(result))
+    }
+}
+
+impl<F: Num + Copy + FromStr> FromStr for DynMatrix<F> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // the first row sets the number of columns
+        let rows: Vec<Row<F>> = parse_rows(text)?;
+        let columns: usize = rows.first().map_or(0, |(_, row)| row.len());
+        let mut entries: Vec<Vec<F>> = Vec::new();
+        for (i, (span, row)) in rows.into_iter().enumerate() {
+            if row.len() != columns {
+                return error(span, format!("expected {} entries in row {}, found {}", columns, i + 1, row.len()));
+            }
+            entries.push(row);
+        }
+
+        Ok(DynMatrix::from_rows(entries, columns).unwrap())
+    }
+}
+
+impl<F: Num + Copy + FromStr, const R: usize> FromStr for Vector<F, R> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // either a single row "[1 2 3]" or a column "[1; 2; 3]"
+        let rows: Vec<Row<F>> = parse_rows(text)?;
+        let entries: Vec<F> = match rows.len() {
+            1 => rows.into_iter().next().unwrap().1,
+            _ if rows.iter().all(|(_, row)| row.len() == 1) => rows.into_iter().map(|(_, row)| row[0]).collect(),
+            _ => return error(0..text.len(), String::from("expected a single row or a single column"))
+        };
+        if entries.len() != R {
+            return error(0..text.len(), format!("expected {} entries, found {}", R, entries.len()));
+        }
+
+        let mut result: [F; R] = [F::zero(); R];
+        result.copy_from_slice(&entries);
+        Ok(Vector::from(result))
+    }
+}
+
+impl<F: Num + Copy + FromStr + Bounded + PartialOrd> FromStr for Polynomial<F> {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // sums of terms like 3x^2, -x, 2*x or 7, in any order and with repeats added up,
+        // the variable can be any single letter as long as it is the same throughout,
+        // coefficients may have an exponent like 1e-300x^2, so 2e-3 is 0.002 and the
+        // polynomial in e needs spaces, 2e - 3
+        let bytes: &[u8] = text.as_bytes();
+        let skip = |mut i: usize| -> usize {
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            i
+        };
+
+        let mut coefficients: Vec<Option<F>> = Vec::new(); // None until a term with that power turns up
+        let mut variable: Option<u8> = None;
+        let mut i: usize = skip(0);
+        if i == bytes.len() {
+            return error(0..text.len(), String::from("expected a polynomial"));
+        }
+
+        let mut first: bool = true;
+        while i < bytes.len() {
+            let term_start: usize = i;
+            let negative: bool = match bytes[i] {
+                b'+' | b'-' => {
+                    let negative: bool = bytes[i] == b'-';
+                    i = skip(i + 1);
+                    negative
+                },
+                _ if first => false,
+                _ => return error(i..(i + 1), String::from("expected '+' or '-'"))
+            };
+            first = false;
+
+            // coefficient
+            let number_start: usize = i;
+            while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'.') {
+                i += 1;
+            }
+            if i > number_start && i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+                // an exponent only when digits follow, otherwise e is the variable
+                let mut digits: usize = i + 1;
+                if digits < bytes.len() && (bytes[digits] == b'+' || bytes[digits] == b'-') {
+                    digits += 1;
+                }
+                if digits < bytes.len() && bytes[digits].is_ascii_digit() {
+                    i = digits;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            // the sign goes into the token, so types without negative numbers reject it
+            let coefficient: Option<F> = match (i > number_start, negative) {
+                (true, true) => Some(number(&format!("-{}", &text[number_start..i]), term_start..i)?),
+                (true, false) => Some(number(&text[number_start..i], number_start..i)?),
+                (false, _) => None
+            };
+            i = skip(i);
+            if coefficient.is_some() && i < bytes.len() && bytes[i] == b'*' {
+                i = skip(i + 1);
+                if i == bytes.len() || !bytes[i].is_ascii_alphabetic() {
+                    return error(i..(i + 1).min(text.len()), String::from("expected a variable after '*'"));
+                }
+            }
+
+            // variable and power
+            let mut power: usize = 0;
+            if i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+                match variable {
+                    Some(letter) if letter != bytes[i] => {
+                        return error(i..(i + 1), format!("expected the variable '{}' used in the earlier terms", letter as char));
+                    },
+                    _ => variable = Some(bytes[i])
+                }
+                power = 1;
+                i = skip(i + 1);
+
+                if i < bytes.len() && bytes[i] == b'^' {
+                    i = skip(i + 1);
+                    let power_start: usize = i;
+                    while i < bytes.len() && bytes[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                    if i == power_start {
+                        return error(power_start..(power_start + 1).min(text.len()), String::from("expected a power after '^'"));
+                    }
+                    power = match text[power_start..i].parse() {
+                        Ok(power) if power <= MAX_DEGREE => power,
+                        _ => return error(power_start..i, format!("the power is above the maximum of {}", MAX_DEGREE))
+                    };
+                    i = skip(i);
+                }
+            } else if coefficient.is_none() {
+                return error(term_start..(i + 1).min(text.len()), String::from("expected a term"));
+            }
+
+            let value: F = match (coefficient, negative) {
+                (Some(value), _) => value,
+                (None, true) => number("-1", term_start..(term_start + 1))?,
+                (None, false) => F::one()
+            };
+            if coefficients.len() <= power {
+                coefficients.resize(power + 1, None);
+            }
+
+            // repeated powers are added up, checked against the bounds of F rather than left to overflow
+            coefficients[power] = match coefficients[power] {
+                Some(sum) if (value > F::zero() && sum > F::max_value() - value) || (value < F::zero() && sum < F::min_value() - value) => {
+                    let term_end: usize = term_start + text[term_start..i].trim_end().len();
+                    return error(term_start..term_end, format!("the coefficients of power {} add up to more than fits", power));
+                },
+                Some(sum) => Some(sum + value),
+                None => Some(value)
+            };
+        }
+
+        Ok(Polynomial::from(coefficients.into_iter().map(|coefficient| coefficient.unwrap_or(F::zero())).collect::<Vec<F>>()))
+    }
+}
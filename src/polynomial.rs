@@ -1,10 +1,32 @@
-use num::{Num, NumCast};
+use num::{Float, Num, NumCast};
+use num::complex::Complex;
 use std::ops;
 
 use crate::vector::Vector;
+use crate::matrix::{Matrix, identity, nan_last};
+use crate::parallel::Element;
 
-#[derive(Debug, Clone, PartialEq)]
-pub struct Polynomial<F: Num + Copy> (Vec<F>);
+pub trait Embed<F: Num + Copy> { // a ring that contains F, so polynomials over F can be evaluated in it
+	fn embed(scalar: F) -> Self;
+}
+impl<F: Num + Copy> Embed<F> for F {
+	fn embed(scalar: F) -> Self {
+		scalar
+	}
+}
+impl<F: Num + Copy> Embed<F> for Complex<F> {
+	fn embed(scalar: F) -> Self {
+		Complex::new(scalar, F::zero())
+	}
+}
+impl<F: Num + Copy + Element, const N: usize> Embed<F> for Matrix<F, N, N> {
+	fn embed(scalar: F) -> Self {
+		identity() * scalar
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Polynomial<F: Num + Copy> (Vec<F>); // normalized so the last coefficient is non-zero, the zero polynomial is empty
 impl<F: Num + Copy> ops::Deref for Polynomial<F> {
     type Target = Vec<F>;
     fn deref(&self) -> &Self::Target {
@@ -16,9 +38,18 @@ impl<F: Num + Copy> ops::DerefMut for Polynomial<F> {
         &mut self.0
     }
 }
+impl<F: Num + Copy> PartialEq for Polynomial<F> {
+	fn eq(&self, other: &Polynomial<F>) -> bool {
+		// DerefMut can leave trailing zeros behind, so compare the significant coefficients only
+		self.coefficients() == other.coefficients()
+	}
+}
 impl<F: Num + Copy> From<Vec<F>> for Polynomial<F> {
 	fn from(vec: Vec<F>) -> Self {
-		Polynomial(vec)
+		let mut polynomial: Polynomial<F> = Polynomial(vec);
+		polynomial.normalize();
+
+		polynomial
 	}
 }
 impl<F: Num + Copy> Into<Vec<F>> for Polynomial<F> {
@@ -67,6 +98,13 @@ impl<F: Num + Copy> ops::Mul<F> for Polynomial<F> {
         pscale(left, self)
     }
 }
+impl<F: Num + Copy> ops::Mul<Polynomial<F>> for Polynomial<F> {
+    type Output = Polynomial<F>;
+
+    fn mul(self, right: Polynomial<F>) -> Polynomial<F> {
+        pmul(self, right)
+    }
+}
 impl<F: Num + Copy> ops::Div<F> for Polynomial<F> {
     type Output = Polynomial<F>;
 
@@ -79,24 +117,186 @@ impl<F: Num + Copy> Polynomial<F> {
 		self.0.len()
 	}
 
-	pub fn degree(&self) -> usize {
-		self.0.len() - 1
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub fn coefficients(&self) -> &[F] {
+		// coefficients up to and including the leading one
+		let mut end: usize = self.0.len();
+		while (end > 0) && (self.0[end - 1] == F::zero()) {
+			end -= 1;
+		}
+
+		&self.0[..end]
+	}
+
+	pub fn normalize(&mut self) {
+		let significant: usize = self.coefficients().len();
+		self.0.truncate(significant);
+	}
+
+	pub fn degree(&self) -> Option<usize> {
+		// the zero polynomial has no degree
+		self.coefficients().len().checked_sub(1)
+	}
+
+	pub fn leading_coefficient(&self) -> Option<F> {
+		self.coefficients().last().copied()
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.coefficients().is_empty()
+	}
+
+	pub fn is_monic(&self) -> bool {
+		self.leading_coefficient() == Some(F::one())
+	}
+
+	pub fn to_monic(&self) -> Polynomial<F> {
+		match self.leading_coefficient() {
+			Some(leading) => pdiv(leading, self.clone()),
+			None => Polynomial(Vec::new())
+		}
 	}
 
 	pub fn at(&self, x: F) -> F {
+		// Horner's scheme
 		let mut y: F = F::zero();
-		for i in 0..self.len() {
-			let mut summand: F = self[i];
-			for _ in 0..i {
-				summand = summand * x;
-			}
-			y = y + summand;
+		for &coefficient in self.coefficients().iter().rev() {
+			y = (y * x) + coefficient;
+		}
+
+		y
+	}
+
+	pub fn eval<T>(&self, x: T) -> T
+	where T: Embed<F> + Copy + ops::Add<T, Output = T> + ops::Mul<T, Output = T> {
+		let mut y: T = T::embed(F::zero());
+		for &coefficient in self.coefficients().iter().rev() {
+			y = (y * x) + T::embed(coefficient);
 		}
 
 		y
 	}
+
+	pub fn at_many(&self, xs: &[F]) -> Vec<F> {
+		xs.iter().map(|&x| self.at(x)).collect()
+	}
+
+	pub fn multipoint(&self, xs: &[F]) -> Vec<F> {
+		// remainder tree over the subproduct tree of (x - x_i),
+		// since p(x_i) = p mod (x - x_i) and the remainders shrink as they go down the tree
+		if xs.is_empty() {
+			return Vec::new();
+		}
+
+		// tree[0] holds the leaves, the last level holds the product of every (x - x_i)
+		let mut tree: Vec<Vec<Polynomial<F>>> = Vec::new();
+		tree.push(xs.iter().map(|&x| Polynomial::from(vec![F::zero() - x, F::one()])).collect());
+		while tree[tree.len() - 1].len() > 1 {
+			let level: &Vec<Polynomial<F>> = &tree[tree.len() - 1];
+			let mut next: Vec<Polynomial<F>> = Vec::new();
+			for pair in level.chunks(2) {
+				match pair {
+					[left, right] => next.push(pmul(left.clone(), right.clone())),
+					_ => next.push(pair[0].clone())
+				}
+			}
+			tree.push(next);
+		}
+
+		let mut remainders: Vec<Polynomial<F>> = vec![pdivrem(self.clone(), tree[tree.len() - 1][0].clone()).1];
+		for level in tree.iter().rev().skip(1) {
+			let mut next: Vec<Polynomial<F>> = Vec::new();
+			for i in 0..level.len() {
+				next.push(pdivrem(remainders[i / 2].clone(), level[i].clone()).1);
+			}
+			remainders = next;
+		}
+
+		remainders.iter().map(|remainder| remainder.at(F::zero())).collect()
+	}
+
+	pub fn interpolate(xs: &[F], ys: &[F]) -> Polynomial<F> {
+		// Newton's divided differences, computed in place
+		assert_eq!(xs.len(), ys.len());
+
+		let mut table: Vec<F> = ys.to_vec();
+		for k in 1..xs.len() {
+			for i in (k..xs.len()).rev() {
+				let run: F = xs[i] - xs[i - k];
+				assert!(run != F::zero(), "interpolation nodes must be distinct");
+				table[i] = (table[i] - table[i - 1]) / run;
+			}
+		}
+
+		newton_form(xs, &table)
+	}
+
+	pub fn shift(&self, a: F) -> Polynomial<F> {
+		// Taylor shift, returns P(x + a) by repeated synthetic division
+		let mut result: Vec<F> = self.coefficients().to_vec();
+		for i in 0..result.len() {
+			for j in (i..(result.len() - 1)).rev() {
+				result[j] = result[j] + (a * result[j + 1]);
+			}
+		}
+
+		Polynomial::from(result)
+	}
+
+	pub fn scale_arg(&self, c: F) -> Polynomial<F> {
+		// returns P(cx)
+		let mut result: Vec<F> = Vec::new();
+		let mut power: F = F::one();
+		for &coefficient in self.coefficients() {
+			result.push(coefficient * power);
+			power = power * c;
+		}
+
+		Polynomial::from(result)
+	}
 }
 impl<F: Num + Copy + NumCast> Polynomial<F> {
+	pub fn hermite_interpolate(xs: &[F], values: &[Vec<F>]) -> Polynomial<F> {
+		// values[i] holds [f(x_i), f'(x_i), f''(x_i), ...], as many derivatives as are known at x_i
+		assert_eq!(xs.len(), values.len());
+
+		// every node is repeated once per known value, and source remembers where it came from
+		let mut nodes: Vec<F> = Vec::new();
+		let mut source: Vec<usize> = Vec::new();
+		for i in 0..xs.len() {
+			for _ in 0..values[i].len() {
+				nodes.push(xs[i]);
+				source.push(i);
+			}
+		}
+
+		let mut table: Vec<F> = Vec::new();
+		for j in 0..nodes.len() {
+			table.push(values[source[j]][0]);
+		}
+
+		let mut factorial: F = F::one();
+		for k in 1..nodes.len() {
+			factorial = factorial * NumCast::from(k).unwrap();
+			for j in (k..nodes.len()).rev() {
+				if source[j] == source[j - k] {
+					// repeated node, so the divided difference is a scaled derivative
+					table[j] = values[source[j]][k] / factorial;
+				}
+				else {
+					let run: F = nodes[j] - nodes[j - k];
+					assert!(run != F::zero(), "interpolation nodes must be distinct");
+					table[j] = (table[j] - table[j - 1]) / run;
+				}
+			}
+		}
+
+		newton_form(&nodes, &table)
+	}
+
 	pub fn derivative(&self) -> Polynomial<F> {
 		let mut result: Vec<F> = Vec::new();
 
@@ -118,6 +318,182 @@ impl<F: Num + Copy + NumCast> Polynomial<F> {
 
 		Polynomial::from(result)
 	}
+
+	pub fn nth_derivative(&self, n: usize) -> Polynomial<F> {
+		// the x^i term becomes i! / (i - n)! x^(i - n)
+		let mut result: Vec<F> = Vec::new();
+		for i in n..self.len() {
+			let mut falling: F = F::one();
+			for k in (i - n + 1)..(i + 1) {
+				falling = falling * NumCast::from(k).unwrap();
+			}
+			result.push(self[i] * falling);
+		}
+
+		Polynomial::from(result)
+	}
+
+	pub fn integral_with_constant(&self, c: F) -> Polynomial<F> {
+		// takes c + \int_{0}^{x} P(t) dt
+		let mut result: Vec<F> = self.integral().into();
+		if result.is_empty() {
+			result.push(F::zero());
+		}
+		result[0] = result[0] + c;
+
+		Polynomial::from(result)
+	}
+
+	pub fn definite_integral(&self, a: F, b: F) -> F {
+		// takes \int_{a}^{b} P(t) dt
+		let antiderivative: Polynomial<F> = self.integral();
+		antiderivative.at(b) - antiderivative.at(a)
+	}
+}
+impl<F: Float> Polynomial<F> {
+	pub fn at_with_error(&self, x: F) -> (F, F) {
+		// Horner's scheme with a running error bound (Higham, Accuracy and Stability, Algorithm 5.1),
+		// returns the value and a bound on its absolute rounding error
+		let coefficients: &[F] = self.coefficients();
+		if coefficients.is_empty() {
+			return (F::zero(), F::zero());
+		}
+
+		let mut y: F = coefficients[coefficients.len() - 1];
+		let mut bound: F = y.abs() / NumCast::from(2).unwrap();
+		for &coefficient in coefficients.iter().rev().skip(1) {
+			y = (y * x) + coefficient;
+			bound = (bound * x.abs()) + y.abs();
+		}
+
+		let unit_roundoff: F = F::epsilon() / NumCast::from(2).unwrap();
+		let error: F = unit_roundoff * ((bound * NumCast::from(2).unwrap()) - y.abs());
+
+		(y, error)
+	}
+
+	pub fn fit(xs: &[F], ys: &[F], degree: usize) -> Polynomial<F> {
+		// least squares by a Householder QR factorization of the Vandermonde matrix V of xs,
+		// the normal equations (V^T V) c = V^T y would square its condition number
+		assert_eq!(xs.len(), ys.len());
+		assert!(xs.len() > degree, "need more points than the degree of the fit");
+
+		let size: usize = degree + 1;
+		let mut v: Vec<Vec<F>> = xs.iter().map(|&x| {
+			let mut power: F = F::one();
+			(0..size).map(|_| {
+				let entry: F = power;
+				power = power * x;
+				entry
+			}).collect()
+		}).collect();
+		let mut y: Vec<F> = ys.to_vec();
+
+		// reflect column k onto the axis, applying the same reflection to the later columns and y
+		for k in 0..size {
+			let norm: F = (k..xs.len()).fold(F::zero(), |sum, i| sum + (v[i][k] * v[i][k])).sqrt();
+			assert!(norm != F::zero(), "need at least degree + 1 distinct points to fit");
+
+			let alpha: F = F::zero() - norm.copysign(v[k][k]);
+			let mut reflector: Vec<F> = (k..xs.len()).map(|i| v[i][k]).collect();
+			reflector[0] = reflector[0] - alpha;
+			let length: F = reflector.iter().fold(F::zero(), |sum, &r| sum + (r * r));
+
+			for j in (k + 1)..size {
+				let dot: F = (k..xs.len()).fold(F::zero(), |sum, i| sum + (reflector[i - k] * v[i][j]));
+				let scale: F = (dot + dot) / length;
+				for i in k..xs.len() {
+					v[i][j] = v[i][j] - (scale * reflector[i - k]);
+				}
+			}
+			let dot: F = (k..xs.len()).fold(F::zero(), |sum, i| sum + (reflector[i - k] * y[i]));
+			let scale: F = (dot + dot) / length;
+			for i in k..xs.len() {
+				y[i] = y[i] - (scale * reflector[i - k]);
+			}
+			v[k][k] = alpha;
+		}
+
+		// back substitution with the triangular R
+		let mut coefficients: Vec<F> = vec![F::zero(); size];
+		for k in (0..size).rev() {
+			let sum: F = ((k + 1)..size).fold(y[k], |sum, j| sum - (v[k][j] * coefficients[j]));
+			coefficients[k] = sum / v[k][k];
+		}
+
+		Polynomial::from(coefficients)
+	}
+
+	pub fn roots(&self) -> Vec<Complex<F>> {
+		// all degree many roots by Durand-Kerner iteration, sorted by real then imaginary part,
+		// imaginary parts within sqrt(epsilon) of zero are snapped to zero
+		let coefficients: &[F] = self.coefficients();
+		let zeros: usize = coefficients.iter().take_while(|&&c| c == F::zero()).count();
+		let mut result: Vec<Complex<F>> = vec![Complex::new(F::zero(), F::zero()); zeros.min(coefficients.len().saturating_sub(1))];
+		if coefficients.len() <= zeros + 1 {
+			return result;
+		}
+
+		// monic, with the roots at zero already factored out
+		let leading: F = coefficients[coefficients.len() - 1];
+		let monic: Vec<Complex<F>> = coefficients[zeros..].iter().map(|&c| Complex::new(c / leading, F::zero())).collect();
+		let degree: usize = monic.len() - 1;
+
+		// start on a circle that holds every root (Cauchy's bound), rotated off the real axis
+		let bound: F = F::one() + monic[..degree].iter().map(|c| c.norm()).fold(F::zero(), F::max);
+		let angle: F = F::from(2.0 * std::f64::consts::PI).unwrap() / F::from(degree).unwrap();
+		let mut z: Vec<Complex<F>> = (0..degree).map(|k| Complex::from_polar(bound, (angle * F::from(k).unwrap()) + F::from(0.4).unwrap())).collect();
+
+		for _ in 0..1000 {
+			let mut converged: bool = true;
+			for k in 0..degree {
+				let mut value: Complex<F> = Complex::new(F::zero(), F::zero());
+				for &c in monic.iter().rev() {
+					value = (value * z[k]) + c;
+				}
+
+				let mut denominator: Complex<F> = Complex::new(F::one(), F::zero());
+				for j in 0..degree {
+					if j != k {
+						denominator = denominator * (z[k] - z[j]);
+					}
+				}
+
+				let step: Complex<F> = value / denominator;
+				z[k] = z[k] - step;
+				converged = converged && step.norm() <= F::epsilon() * F::from(4).unwrap() * z[k].norm().max(F::one());
+			}
+
+			if converged {
+				break;
+			}
+		}
+
+		for root in z {
+			let snapped: bool = root.im.abs() <= F::epsilon().sqrt() * root.norm().max(F::one());
+			result.push(if snapped { Complex::new(root.re, F::zero()) } else { root });
+		}
+		result.sort_by(|a, b| nan_last(a.re, b.re).then(nan_last(a.im, b.im)));
+
+		result
+	}
+}
+
+fn newton_form<F: Num + Copy>(nodes: &[F], coefficients: &[F]) -> Polynomial<F> {
+	// expands c_0 + c_1 (x - z_0) + c_2 (x - z_0)(x - z_1) + ... using Horner's scheme
+	let mut result: Vec<F> = Vec::new();
+	for k in (0..coefficients.len()).rev() {
+		// result = result * (x - z_k) + c_k
+		let mut shifted: Vec<F> = vec![F::zero(); result.len() + 1];
+		for i in 0..result.len() {
+			shifted[i + 1] = shifted[i + 1] + result[i];
+			shifted[i] = shifted[i] - (nodes[k] * result[i]);
+		}
+		shifted[0] = shifted[0] + coefficients[k];
+		result = shifted;
+	}
+
+	Polynomial::from(result)
 }
 
 pub fn pscale<F: Num + Copy>(scalar: F, polynomial: Polynomial<F>) -> Polynomial<F> { 
@@ -179,4 +555,58 @@ pub fn psub<F: Num + Copy>(left: Polynomial<F>, right: Polynomial<F>) -> Polynom
 	}
 
     Polynomial::from(result)
-}
\ No newline at end of file
+}
+
+pub fn pmul<F: Num + Copy>(left: Polynomial<F>, right: Polynomial<F>) -> Polynomial<F> {
+    if left.is_zero() || right.is_zero() {
+        return Polynomial::from(Vec::new());
+    }
+
+    let mut result: Vec<F> = vec![F::zero(); left.len() + right.len() - 1];
+    for i in 0..left.len() {
+        for j in 0..right.len() {
+            result[i + j] = result[i + j] + (left[i] * right[j]);
+        }
+    }
+
+    Polynomial::from(result)
+}
+
+pub fn compose<F: Num + Copy>(outer: Polynomial<F>, inner: Polynomial<F>) -> Polynomial<F> {
+    // returns outer(inner(x)) using Horner's scheme over polynomials
+    let mut result: Polynomial<F> = Polynomial::from(Vec::new());
+    for &coefficient in outer.coefficients().iter().rev() {
+        result = padd(pmul(result, inner.clone()), Polynomial::from(vec![coefficient]));
+    }
+
+    result
+}
+
+pub fn pdivrem<F: Num + Copy>(dividend: Polynomial<F>, divisor: Polynomial<F>) -> (Polynomial<F>, Polynomial<F>) {
+    // long division, returns (quotient, remainder), over the integers the divisor has to be monic
+    // or at least have a leading coefficient that divides every step, otherwise this panics
+    let divisor: Vec<F> = divisor.coefficients().to_vec();
+    assert!(!divisor.is_empty(), "division by the zero polynomial");
+
+    let mut remainder: Vec<F> = dividend.coefficients().to_vec();
+    if remainder.len() < divisor.len() {
+        return (Polynomial::from(Vec::new()), Polynomial::from(remainder));
+    }
+
+    let leading: F = divisor[divisor.len() - 1];
+    // 1 / leading is only zero when division truncates, then every step has to come out exact
+    let truncating: bool = F::one() / leading == F::zero();
+    let mut quotient: Vec<F> = vec![F::zero(); remainder.len() - divisor.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let top: F = remainder[i + divisor.len() - 1];
+        let factor: F = top / leading;
+        assert!(!truncating || factor * leading == top, "the leading coefficient of the divisor does not divide the dividend, integer divisors should be monic");
+        quotient[i] = factor;
+        for j in 0..divisor.len() {
+            remainder[i + j] = remainder[i + j] - (factor * divisor[j]);
+        }
+    }
+
+    remainder.truncate(divisor.len() - 1);
+    (Polynomial::from(quotient), Polynomial::from(remainder))
+}
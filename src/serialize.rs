@@ -0,0 +1,127 @@
+use num::Num;
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+
+use crate::vector::Vector;
+use crate::matrix::Matrix;
+use crate::polynomial::Polynomial;
+use crate::graph::Graph;
+
+// Vectors and polynomials are plain sequences and matrices and graphs are sequences of rows,
+// so [[1, 2], [3, 4]] in JSON. The dimensions are part of the type, so deserializing checks
+// them and fails with a message naming the expected and found sizes.
+
+fn exact<F, E: Error, const R: usize>(values: Vec<F>, what: &str) -> Result<[F; R], E> {
+    let found: usize = values.len();
+    values.try_into().map_err(|_| E::custom(format!("expected {} with {} entries, found {}", what, R, found)))
+}
+
+fn rows<F, E: Error, const R: usize, const C: usize>(values: Vec<Vec<F>>, what: &str) -> Result<[[F; C]; R], E> {
+    if values.len() != R {
+        return Err(E::custom(format!("expected {} with {} rows, found {}", what, R, values.len())));
+    }
+
+    let mut result: Vec<[F; C]> = Vec::new();
+    for (i, row) in values.into_iter().enumerate() {
+        let found: usize = row.len();
+        result.push(row.try_into().map_err(|_| E::custom(format!("expected {} columns in row {} of {}, found {}", C, i, what, found)))?);
+    }
+
+    exact(result, what)
+}
+
+impl<F: Num + Copy + Serialize, const R: usize> Serialize for Vector<F, R> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+impl<'de, F: Num + Copy + Deserialize<'de>, const R: usize> Deserialize<'de> for Vector<F, R> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values: Vec<F> = Vec::deserialize(deserializer)?;
+        Ok(Vector::from(exact(values, "a vector")?))
+    }
+}
+
+impl<F: Num + Copy + Serialize, const R: usize, const C: usize> Serialize for Matrix<F, R, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter().map(|row| row.as_slice()))
+    }
+}
+impl<'de, F: Num + Copy + Deserialize<'de>, const R: usize, const C: usize> Deserialize<'de> for Matrix<F, R, C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values: Vec<Vec<F>> = Vec::deserialize(deserializer)?;
+        Ok(Matrix::from(rows(values, "a matrix")?))
+    }
+}
+
+impl<F: Num + Copy + Serialize> Serialize for Polynomial<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // constant term first, trailing zeros left out
+        serializer.collect_seq(self.coefficients())
+    }
+}
+impl<'de, F: Num + Copy + Deserialize<'de>> Deserialize<'de> for Polynomial<F> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let coefficients: Vec<F> = Vec::deserialize(deserializer)?;
+        Ok(Polynomial::from(coefficients))
+    }
+}
+
+impl<const N: usize> Serialize for Graph<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // the adjacency array, entries count parallel edges
+        let adjacency: [[u8; N]; N] = (*self).into();
+        serializer.collect_seq(adjacency.iter().map(|row| row.as_slice()))
+    }
+}
+impl<'de, const N: usize> Deserialize<'de> for Graph<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values: Vec<Vec<u8>> = Vec::deserialize(deserializer)?;
+        Ok(Graph::from(rows(values, "an adjacency array")?))
+    }
+}
+
+pub mod flat {
+    // the {rows, cols, data} layout with the entries in row major order, for fields marked
+    // #[serde(with = "la::serialize::flat")]
+    use num::Num;
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    use serde::de::Error;
+
+    use crate::matrix::Matrix;
+
+    #[derive(Serialize)]
+    struct FlatRef<'a, F> {
+        rows: usize,
+        cols: usize,
+        data: Vec<&'a F>
+    }
+
+    #[derive(Deserialize)]
+    struct Flat<F> {
+        rows: usize,
+        cols: usize,
+        data: Vec<F>
+    }
+
+    pub fn serialize<F: Num + Copy + Serialize, S: Serializer, const R: usize, const C: usize>(matrix: &Matrix<F, R, C>, serializer: S) -> Result<S::Ok, S::Error> {
+        FlatRef { rows: R, cols: C, data: matrix.iter().flatten().collect() }.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, F: Num + Copy + Deserialize<'de>, D: Deserializer<'de>, const R: usize, const C: usize>(deserializer: D) -> Result<Matrix<F, R, C>, D::Error> {
+        let flat: Flat<F> = Flat::deserialize(deserializer)?;
+        if (flat.rows, flat.cols) != (R, C) {
+            return Err(D::Error::custom(format!("expected a {}x{} matrix, found {}x{}", R, C, flat.rows, flat.cols)));
+        }
+        if flat.data.len() != R * C {
+            return Err(D::Error::custom(format!("expected {} entries for a {}x{} matrix, found {}", R * C, R, C, flat.data.len())));
+        }
+
+        let mut result: [[F; C]; R] = [[F::zero(); C]; R];
+        for (k, value) in flat.data.into_iter().enumerate() {
+            result[k / C][k % C] = value;
+        }
+
+        Ok(Matrix::from(result))
+    }
+}
@@ -0,0 +1,51 @@
+use la::display::*;
+use la::matrix::*;
+use la::vector::*;
+use la::polynomial::*;
+use num::complex::Complex;
+
+#[test]
+fn matrix_display() {
+    let matrix: Matrix<f64, 2, 3> = Matrix::from([[1.0, -2.5, 100.0], [-30.25, 0.0, 4.0]]);
+    assert_eq!(format!("{}", matrix), "[     1  -2.5  100]\n[-30.25     0    4]");
+    assert_eq!(format!("{:.2}", matrix), "[  1.00  -2.50  100.00]\n[-30.25   0.00    4.00]");
+    assert_eq!(format!("{:4}", Matrix::from([[1, 2], [3, 4]])), "[   1     2]\n[   3     4]");
+    assert_eq!(format!("{}", Matrix::<i32, 0, 0>::from([])), "[]");
+}
+
+#[test]
+fn vector_display() {
+    let vector: Vector<f64, 3> = Vector::from([1.0, -0.5, 2.25]);
+    assert_eq!(format!("{}", vector), "[1, -0.5, 2.25]");
+    assert_eq!(format!("{:.1}", vector), "[1.0, -0.5, 2.2]");
+    assert_eq!(format!("{:3}", Vector::from([1, 20])), "[  1,  20]");
+}
+
+#[test]
+fn polynomial_display() {
+    assert_eq!(format!("{}", Polynomial::from(vec![1, -1, 3])), "3x^2 - x + 1");
+    assert_eq!(format!("{}", Polynomial::from(vec![0, 1])), "x");
+    assert_eq!(format!("{}", Polynomial::from(vec![-4, 0, 0, -1])), "-x^3 - 4");
+    assert_eq!(format!("{}", Polynomial::<i32>::from(vec![])), "0");
+    assert_eq!(format!("{:.2}", Polynomial::from(vec![0.5, 0.0, 2.0])), "2.00x^2 + 0.50");
+    assert_eq!(format!("{:8}", Polynomial::from(vec![1, 1])), "   x + 1");
+
+    let complex: Polynomial<Complex<i32>> = Polynomial::from(vec![Complex::new(1, 0), Complex::new(2, -3)]);
+    assert_eq!(format!("{}", complex), "(2-3i)x + (1+0i)");
+}
+
+#[test]
+fn latex() {
+    let matrix: Matrix<i32, 2, 2> = Matrix::from([[1, -2], [3, 4]]);
+    assert_eq!(matrix.to_latex(Delimiter::Brackets), "\\begin{bmatrix}\n1 & -2 \\\\\n3 & 4\n\\end{bmatrix}");
+    assert_eq!(Vector::from([5, 6]).to_latex(Delimiter::Parentheses), "\\begin{pmatrix}\n5 \\\\\n6\n\\end{pmatrix}");
+    assert_eq!(Polynomial::from(vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 0, -2]).to_latex(), "-2x^{10} + 1");
+}
+
+#[test]
+fn markdown() {
+    let matrix: Matrix<i32, 2, 2> = Matrix::from([[1, -2], [3, 4]]);
+    assert_eq!(matrix.to_markdown(Some(&["a", "b|c"])), "| a | b\\|c |\n|---:|---:|\n| 1 | -2 |\n| 3 | 4 |\n");
+    assert_eq!(matrix.to_markdown(None), "|  |  |\n|---:|---:|\n| 1 | -2 |\n| 3 | 4 |\n");
+    assert_eq!(Vector::from([7, 8]).to_markdown(Some("x")), "| x |\n|---:|\n| 7 |\n| 8 |\n");
+}
@@ -0,0 +1,136 @@
+use la::dynamic::*;
+use la::matrix::*;
+use num::complex::Complex;
+
+#[test]
+fn conversions() {
+    let matrix: Matrix<i32, 2, 3> = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    let dynamic: DynMatrix<i32> = DynMatrix::from(matrix);
+    assert_eq!((dynamic.rows(), dynamic.columns()), (2, 3));
+    assert_eq!(dynamic[1][2], 6);
+    assert_eq!(dynamic.transpose().to_rows(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    assert_eq!(Matrix::<i32, 2, 3>::try_from(dynamic.clone()), Ok(matrix));
+    assert_eq!(Matrix::<i32, 3, 2>::try_from(dynamic), Err(DimensionMismatch { expected: (3, 2), found: (2, 3) }));
+
+    assert!(DynMatrix::from_rows(vec![vec![1, 2], vec![3]], 2).is_err());
+    assert_eq!(DynMatrix::<i32>::from_rows(vec![], 3).unwrap().columns(), 3);
+
+    let a: DynMatrix<i32> = DynMatrix::new(2, 2, vec![1, 2, 3, 4]);
+    assert_eq!((&a * &DynMatrix::identity(2)), a);
+    assert_eq!((&a * &a).to_rows(), vec![vec![7, 10], vec![15, 22]]);
+}
+
+#[test]
+fn arithmetic() {
+    let a: DynMatrix<i32> = DynMatrix::new(2, 2, vec![1, 2, 3, 4]);
+    let b: DynMatrix<i32> = DynMatrix::new(2, 2, vec![0, 1, 1, 0]);
+    assert_eq!((&a + &b).to_rows(), vec![vec![1, 3], vec![4, 4]]);
+    assert_eq!((&a - &b).to_rows(), vec![vec![1, 1], vec![2, 4]]);
+    assert_eq!((&a * 2).to_rows(), vec![vec![2, 4], vec![6, 8]]);
+    assert_eq!((&(&a * 2) / 2), a);
+    assert_eq!(a.trace(), 5);
+
+    assert_eq!(a.pow(0), DynMatrix::identity(2));
+    assert_eq!(a.pow(3), &(&a * &a) * &a);
+}
+
+#[test]
+#[should_panic]
+fn adding_different_sizes() {
+    let _ = &DynMatrix::<i32>::zeros(2, 2) + &DynMatrix::zeros(2, 3);
+}
+
+#[test]
+#[should_panic(expected = "too many entries")]
+fn overflowing_size() {
+    let _ = DynMatrix::<f64>::zeros(1 << 40, 1 << 40);
+}
+
+#[test]
+fn elimination() {
+    let a: DynMatrix<f64> = DynMatrix::new(3, 3, vec![2.0, 1.0, 1.0, 1.0, 3.0, 2.0, 1.0, 0.0, 0.0]);
+    assert!((a.determinant() - (-1.0)).abs() < 1e-12);
+    assert_eq!(a.rank(), 3);
+
+    let inverse: DynMatrix<f64> = a.inverse().unwrap();
+    let product: DynMatrix<f64> = &a * &inverse;
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((product[i][j] - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+        }
+    }
+
+    let x: Vec<f64> = a.solve(&[4.0, 5.0, 6.0]).unwrap();
+    for i in 0..3 {
+        let row: f64 = (0..3).map(|j| a[i][j] * x[j]).sum();
+        assert!((row - [4.0, 5.0, 6.0][i]).abs() < 1e-12);
+    }
+
+    let singular: DynMatrix<f64> = DynMatrix::new(2, 3, vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0]);
+    assert_eq!(singular.rank(), 1);
+    assert_eq!(singular.rref().to_rows(), vec![vec![1.0, 2.0, 3.0], vec![0.0, 0.0, 0.0]]);
+    assert_eq!(DynMatrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).inverse(), None);
+    assert_eq!(DynMatrix::new(2, 2, vec![1.0, 2.0, 2.0, 4.0]).determinant(), 0.0);
+
+    // NaN spreads into the results instead of panicking in the pivot search
+    let nan: DynMatrix<f64> = DynMatrix::new(2, 2, vec![f64::NAN, 1.0, 1.0, 1.0]);
+    assert!(nan.determinant().is_nan());
+    assert!(nan.rank() <= 2);
+    assert!(nan.eigenvalues().iter().all(|value| value.re.is_nan()));
+    assert!(nan.symmetric_eigen().unwrap().0.iter().all(|value| value.is_nan()));
+}
+
+#[test]
+fn eigenvalues() {
+    let a: DynMatrix<f64> = DynMatrix::new(2, 2, vec![1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(a.characteristic_polynomial().coefficients(), &[-2.0, -5.0, 1.0]);
+
+    // a rotation has no real eigenvalues
+    let rotation: DynMatrix<f64> = DynMatrix::new(2, 2, vec![0.0, -1.0, 1.0, 0.0]);
+    let values: Vec<Complex<f64>> = rotation.eigenvalues();
+    assert!((values[0] - Complex::new(0.0, -1.0)).norm() < 1e-9);
+    assert!((values[1] - Complex::new(0.0, 1.0)).norm() < 1e-9);
+    assert_eq!(rotation.symmetric_eigen(), None);
+
+    // Jordan blocks are already triangular and give their eigenvalue exactly
+    let jordan: DynMatrix<f64> = "[1 1 0; 0 1 1; 0 0 1]".parse().unwrap();
+    assert_eq!(jordan.eigenvalues(), vec![Complex::new(1.0, 0.0); 3]);
+    let jordan: DynMatrix<f64> = "[2 1; 0 2]".parse().unwrap();
+    assert_eq!(jordan.eigenvalues(), vec![Complex::new(2.0, 0.0); 2]);
+
+    // a full matrix, the eigenvalues add up to the trace and multiply to the determinant
+    let a: DynMatrix<f64> = "[4 1 2 3; 1 5 1 2; 0 2 6 1; 7 1 1 1]".parse().unwrap();
+    let values: Vec<Complex<f64>> = a.eigenvalues();
+    let roots: Vec<Complex<f64>> = a.characteristic_polynomial().roots();
+    assert!(values.iter().zip(&roots).all(|(value, root)| (value - root).norm() < 1e-9));
+    assert!((values.iter().sum::<Complex<f64>>() - Complex::new(a.trace(), 0.0)).norm() < 1e-9);
+    assert!((values.iter().product::<Complex<f64>>() - Complex::new(a.determinant(), 0.0)).norm() < 1e-9);
+
+    let symmetric: DynMatrix<f64> = DynMatrix::new(2, 2, vec![2.0, 1.0, 1.0, 2.0]);
+    let (values, vectors) = symmetric.symmetric_eigen().unwrap();
+    assert!((values[0] - 1.0).abs() < 1e-12 && (values[1] - 3.0).abs() < 1e-12);
+    for k in 0..2 {
+        for i in 0..2 {
+            let image: f64 = (0..2).map(|j| symmetric[i][j] * vectors[j][k]).sum();
+            assert!((image - values[k] * vectors[i][k]).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn reading_and_writing() {
+    let a: DynMatrix<f64> = "[1 2 3; 4 5 6]".parse().unwrap();
+    assert_eq!((a.rows(), a.columns()), (2, 3));
+    assert_eq!("[[1, 2], [3]]".parse::<DynMatrix<f64>>().unwrap_err().span, 9..12);
+
+    assert_eq!(DynMatrix::<f64>::from_csv("a,b\n1,2\n3,4\n", ',', true).unwrap().to_rows(), vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let text: &str = "%%MatrixMarket matrix coordinate integer general\n2 3 1\n2 3 7\n";
+    assert_eq!(DynMatrix::<i32>::from_matrix_market(text).unwrap().to_rows(), vec![vec![0, 0, 0], vec![0, 0, 7]]);
+    for size in ["100000000 100000000", "4000000000 4000000000 1", "18446744073709551615 2 1"] {
+        let huge: String = format!("%%MatrixMarket matrix coordinate integer general\n{}\n1 1 1\n", size);
+        assert!(matches!(DynMatrix::<i32>::from_matrix_market(&huge), Err(la::io::MatrixFormatError::Parse { line: 2, .. })));
+    }
+
+    assert_eq!(format!("{}", a), "[1  2  3]\n[4  5  6]");
+    assert_eq!(a.to_latex(la::display::Delimiter::Parentheses), "\\begin{pmatrix}\n1 & 2 & 3 \\\\\n4 & 5 & 6\n\\end{pmatrix}");
+}
@@ -1 +1,556 @@
+use la::graph::*;
+use la::matrix::*;
 
+#[test]
+fn walk_counting() {
+    // triangle, the number of closed walks of length 3 from a vertex is 2
+    let g: Graph<3> = Graph::from([[0,1,1],[1,0,1],[1,1,0]]);
+    assert_eq!(g.count_walks(0, 0, 3), 2);
+    assert_eq!(g.count_walks(0, 1, 2), 1);
+
+    let w: Matrix<u64, 3, 3> = Matrix::from([[2,1,1],[1,2,1],[1,1,2]]);
+    assert_eq!(g.walk_matrix(2), w);
+}
+
+#[test]
+fn walk_counting_does_not_overflow() {
+    // K4 has (3^20 + 3) / 4 closed walks of length 20 at each vertex, well past u8
+    let g: Graph<4> = Graph::complete();
+    assert_eq!(g.count_walks(0, 0, 20), 871696101);
+}
+
+#[test]
+fn simple_path_counting() {
+    // square 0-1-2-3-0 has two simple paths between opposite corners
+    let g: Graph<4> = Graph::cycle();
+    assert_eq!(g.count_simple_paths(0, 2), 2);
+    assert_eq!(g.count_simple_paths(0, 1), 2);
+}
+
+#[test]
+fn cycle_counting() {
+    let g: Graph<3> = Graph::from([[0,1,0],[0,0,1],[1,0,0]]);
+    assert_eq!(g.count_cycles(3), 1);
+    assert_eq!(g.count_cycles(2), 0);
+
+    let k4: Graph<4> = Graph::complete();
+    assert_eq!(k4.count_cycles(3) / 2, 4);
+    assert_eq!(k4.count_cycles(4) / 2, 3);
+}
+
+#[test]
+fn breadth_first_distances() {
+    // path 0-1-2 plus an isolated vertex 3
+    let g: Graph<4> = Graph::from([[0,1,0,0],[1,0,1,0],[0,1,0,0],[0,0,0,0]]);
+    let paths: ShortestPaths<usize, 4> = g.bfs_distances(0);
+    assert_eq!(paths.distances(), [Some(0), Some(1), Some(2), None]);
+    assert_eq!(g.shortest_path(0, 2), Some(vec![0, 1, 2]));
+    assert_eq!(g.shortest_path(0, 3), None);
+}
+
+#[test]
+fn dijkstra_shortest_paths() {
+    let g: WeightedGraph<f64, 4> = WeightedGraph::from(Matrix::from([
+        [0.0, 4.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+        [0.0, 2.0, 0.0, 5.0],
+        [0.0, 0.0, 0.0, 0.0]
+    ]));
+    let paths: ShortestPaths<f64, 4> = g.dijkstra(0);
+    assert_eq!(paths.distance(3), Some(4.0));
+    assert_eq!(paths.path_to(3), Some(vec![0, 2, 1, 3]));
+    assert_eq!(g.dijkstra(3).path_to(0), None);
+}
+
+#[test]
+fn bellman_ford_shortest_paths() {
+    let g: WeightedGraph<i32, 3> = WeightedGraph::from([
+        [None, Some(4), Some(5)],
+        [None, None, None],
+        [None, Some(-3), None]
+    ]);
+    let paths: ShortestPaths<i32, 3> = g.bellman_ford(0).unwrap();
+    assert_eq!(paths.distance(1), Some(2));
+    assert_eq!(paths.path_to(1), Some(vec![0, 2, 1]));
+}
+
+#[test]
+fn negative_cycle_detection() {
+    let g: WeightedGraph<i32, 3> = WeightedGraph::from([
+        [None, Some(1), None],
+        [None, None, Some(-2)],
+        [None, Some(1), None]
+    ]);
+    let cycle: NegativeCycle = g.bellman_ford(0).unwrap_err();
+    let mut vertices: Vec<usize> = cycle.0.clone();
+    vertices.sort();
+    assert_eq!(vertices, vec![1, 2]);
+    assert!(g.floyd_warshall().is_err());
+}
+
+#[test]
+fn floyd_warshall_all_pairs() {
+    let g: WeightedGraph<u32, 3> = WeightedGraph::from(Graph::from([[0,1,5],[0,0,1],[1,0,0]]));
+    let paths: AllPairsShortestPaths<u32, 3> = g.floyd_warshall().unwrap();
+    let d: Matrix<u32, 3, 3> = Matrix::from([[0,1,2],[2,0,1],[1,2,0]]);
+    assert_eq!(paths.distance_matrix(u32::MAX), d);
+    assert_eq!(paths.path(0, 2), Some(vec![0, 1, 2]));
+    assert_eq!(paths.path(1, 0), Some(vec![1, 2, 0]));
+}
+
+#[test]
+fn depth_and_breadth_first_iteration() {
+    // 0 -> 1 -> 3, 0 -> 2
+    let g: Graph<4> = Graph::from([[0,1,1,0],[0,0,0,1],[0,0,0,0],[0,0,0,0]]);
+    assert_eq!(g.dfs(0).collect::<Vec<usize>>(), vec![0, 1, 3, 2]);
+    assert_eq!(g.bfs(0).collect::<Vec<usize>>(), vec![0, 1, 2, 3]);
+    assert_eq!(g.dfs(1).collect::<Vec<usize>>(), vec![1, 3]);
+}
+
+#[test]
+fn connected_components() {
+    let g: Graph<5> = Graph::from([
+        [0,1,0,0,0],
+        [0,0,0,0,0],
+        [0,0,0,1,0],
+        [0,0,1,0,0],
+        [0,0,0,0,0]
+    ]);
+    assert_eq!(g.connected_components(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+}
+
+#[test]
+fn strongly_connected_components() {
+    // 0 <-> 1 -> 2 <-> 3
+    let g: Graph<4> = Graph::from([[0,1,0,0],[1,0,1,0],[0,0,0,1],[0,0,1,0]]);
+    let mut components: Vec<Vec<usize>> = g.strongly_connected_components();
+    components.sort();
+    assert_eq!(components, vec![vec![0, 1], vec![2, 3]]);
+}
+
+#[test]
+fn topological_sorting() {
+    let g: Graph<4> = Graph::from([[0,0,1,0],[1,0,0,0],[0,0,0,0],[0,1,0,0]]);
+    assert_eq!(g.topological_sort(), Ok(vec![3, 1, 0, 2]));
+
+    let h: Graph<3> = Graph::from([[0,1,0],[0,0,1],[1,0,0]]);
+    assert_eq!(h.topological_sort(), Err(Cycle(vec![0, 1, 2])));
+}
+
+#[test]
+fn bipartiteness() {
+    let square: Graph<4> = Graph::cycle();
+    assert_eq!(square.bipartition(), Some([false, true, false, true]));
+
+    let triangle: Graph<3> = Graph::from([[0,1,1],[1,0,1],[1,1,0]]);
+    assert!(!triangle.is_bipartite());
+}
+
+#[test]
+fn articulation_points_and_bridges() {
+    // triangle 0-1-2 with a tail 2-3
+    let g: Graph<4> = Graph::from([[0,1,1,0],[1,0,1,0],[1,1,0,1],[0,0,1,0]]);
+    assert_eq!(g.articulation_points(), vec![2]);
+    assert_eq!(g.bridges(), vec![(2, 3)]);
+
+    // a doubled edge is not a bridge
+    let h: Graph<2> = Graph::from([[0,2],[2,0]]);
+    assert_eq!(h.bridges(), vec![]);
+}
+
+#[test]
+fn laplacian_matrices() {
+    // path 0-1-2
+    let g: Graph<3> = Graph::from([[0,1,0],[1,0,1],[0,1,0]]);
+    let d: Matrix<i32, 3, 3> = Matrix::from([[1,0,0],[0,2,0],[0,0,1]]);
+    let l: Matrix<i32, 3, 3> = Matrix::from([[1,-1,0],[-1,2,-1],[0,-1,1]]);
+    assert_eq!(g.degree_matrix(), d);
+    assert_eq!(g.laplacian(), l);
+
+    let n: Matrix<f64, 3, 3> = g.normalized_laplacian();
+    assert!((n[0][1] + 0.5_f64.sqrt()).abs() < 1e-12);
+    assert_eq!(n[1][1], 1.0);
+}
+
+#[test]
+fn spectral_partitioning() {
+    // two triangles joined by the edge 2-3
+    let g: Graph<6> = Graph::from([
+        [0,1,1,0,0,0],
+        [1,0,1,0,0,0],
+        [1,1,0,1,0,0],
+        [0,0,1,0,1,1],
+        [0,0,0,1,0,1],
+        [0,0,0,1,1,0]
+    ]);
+    let halves: [bool; 6] = g.spectral_bisection();
+    assert_eq!(halves[0], halves[1]);
+    assert_eq!(halves[1], halves[2]);
+    assert_ne!(halves[2], halves[3]);
+    assert_eq!(halves[3], halves[4]);
+    assert_eq!(halves[4], halves[5]);
+
+    let clusters: [usize; 6] = g.spectral_clustering(2);
+    assert_eq!(clusters[0], clusters[2]);
+    assert_ne!(clusters[0], clusters[3]);
+    assert_eq!(clusters[3], clusters[5]);
+}
+
+#[test]
+fn spanning_trees() {
+    // Cayley's formula, K4 has 4^2 spanning trees
+    let k4: Graph<4> = Graph::complete();
+    assert_eq!(k4.spanning_tree_count(), 16);
+
+    let square: Graph<4> = Graph::cycle();
+    assert_eq!(square.spanning_tree_count(), 4);
+}
+
+#[test]
+fn edge_list_construction() {
+    let mut g: Graph<3> = Graph::from_edges(&[(0, 1), (1, 2), (1, 2)]);
+    assert_eq!(g, Graph::from([[0,1,0],[0,0,2],[0,0,0]]));
+    assert!(!g.is_undirected());
+    assert_eq!(g.neighbors(1).collect::<Vec<usize>>(), vec![2]);
+    assert_eq!(g.in_neighbors(2).collect::<Vec<usize>>(), vec![1]);
+
+    assert!(g.remove_edge(1, 2));
+    assert!(g.has_edge(1, 2));
+    assert!(g.remove_edge(1, 2));
+    assert!(!g.has_edge(1, 2));
+    assert!(!g.remove_edge(1, 2));
+
+    g.add_undirected_edge(0, 2);
+    assert_eq!(g.edges(), vec![(0, 1), (0, 2), (2, 0)]);
+
+    let h: Graph<3> = Graph::undirected_from_edges(&[(0, 1), (1, 2)]);
+    assert!(h.is_undirected());
+    assert_eq!(h, Graph::path());
+}
+
+#[test]
+fn standard_generators() {
+    let star: Graph<4> = Graph::star();
+    assert_eq!(star.degrees(), [3, 1, 1, 1]);
+
+    // 2 x 3 grid has 7 edges
+    let grid: Graph<6> = Graph::grid(2, 3);
+    assert_eq!(grid.edges().len(), 14);
+    assert!(grid.has_edge(1, 4));
+    assert!(!grid.has_edge(2, 3));
+
+    let cube: Graph<8> = Graph::hypercube();
+    assert_eq!(cube.degrees(), [3; 8]);
+    assert!(cube.is_bipartite());
+
+    let petersen: Graph<10> = Graph::petersen();
+    assert_eq!(petersen.degrees(), [3; 10]);
+    assert_eq!(petersen.count_cycles(5) / 2, 12);
+    assert_eq!(petersen.count_cycles(4), 0);
+}
+
+#[test]
+fn seeded_random_graphs() {
+    let a: Graph<20> = Graph::erdos_renyi(0.3, 7);
+    let b: Graph<20> = Graph::erdos_renyi(0.3, 7);
+    assert_eq!(a, b);
+    assert!(a.is_undirected());
+
+    assert_eq!(Graph::<20>::erdos_renyi(0.0, 7), Graph::empty());
+    assert_eq!(Graph::<20>::erdos_renyi(1.0, 7), Graph::complete());
+}
+
+#[test]
+fn dot_export() {
+    let g: Graph<3> = Graph::path();
+    assert_eq!(g.to_dot(), "graph G {\n    0;\n    1;\n    2;\n    0 -- 1;\n    1 -- 2;\n}\n");
+
+    let h: Graph<2> = Graph::from_edges(&[(0, 1)]);
+    assert_eq!(h.to_dot(), "digraph G {\n    0;\n    1;\n    0 -> 1;\n}\n");
+}
+
+#[test]
+fn edge_list_round_trip() {
+    let g: Graph<4> = Graph::from_edges(&[(0, 1), (1, 2), (1, 2), (3, 0)]);
+    assert_eq!(Graph::from_edge_list(&g.to_edge_list()), Ok(g));
+
+    let parsed: Result<Graph<3>, GraphFormatError> = Graph::from_edge_list("# comment\n0, 1\n\n1 2\n");
+    assert_eq!(parsed, Ok(Graph::from_edges(&[(0, 1), (1, 2)])));
+}
+
+#[test]
+fn edge_list_errors() {
+    let bad: Result<Graph<3>, GraphFormatError> = Graph::from_edge_list("0 1\n1 x\n");
+    assert_eq!(bad, Err(GraphFormatError::Parse { line: 2, message: String::from("expected a number, found 'x'") }));
+
+    let too_big: Result<Graph<3>, GraphFormatError> = Graph::from_edge_list("0 5\n");
+    assert_eq!(too_big, Err(GraphFormatError::Parse { line: 1, message: String::from("vertex 5 is out of range for a graph with 3 vertices") }));
+}
+
+#[test]
+fn csv_round_trip() {
+    let g: Graph<3> = Graph::from([[0,2,0],[1,0,1],[0,0,0]]);
+    assert_eq!(g.to_csv(), "0,2,0\n1,0,1\n0,0,0\n");
+    assert_eq!(Graph::from_csv(&g.to_csv()), Ok(g));
+    assert_eq!(Graph::<2>::from_csv(&g.to_csv()), Err(GraphFormatError::Parse { line: 1, message: String::from("expected 2 fields, found 3") }));
+    assert_eq!(Graph::<2>::from_csv("0,1\n1,0\n\n1,1\n"), Err(GraphFormatError::Parse { line: 4, message: String::from("expected 2 rows, found more") }));
+    assert_eq!(Graph::<3>::from_csv("0,1,0\n"), Err(GraphFormatError::SizeMismatch { expected: 3, found: 1 }));
+}
+
+#[test]
+fn matrix_market_round_trip() {
+    let g: Graph<4> = Graph::cycle();
+    assert_eq!(Graph::from_matrix_market(&g.to_matrix_market()), Ok(g));
+
+    let symmetric: &str = "%%MatrixMarket matrix coordinate pattern symmetric\n% comment\n3 3 2\n2 1\n3 2\n";
+    assert_eq!(Graph::from_matrix_market(symmetric), Ok(Graph::<3>::path()));
+
+    let wrong_size: Result<Graph<4>, GraphFormatError> = Graph::from_matrix_market(symmetric);
+    assert_eq!(wrong_size, Err(GraphFormatError::SizeMismatch { expected: 4, found: 3 }));
+
+    let rectangular: &str = "%%MatrixMarket matrix coordinate pattern general\n3 5 1\n1 2\n";
+    let message: String = String::from("expected a square adjacency matrix, found 3x5");
+    assert_eq!(Graph::<5>::from_matrix_market(rectangular), Err(GraphFormatError::Parse { line: 2, message }));
+
+    // the entry count on the size line has to match the entry lines
+    let short: &str = "%%MatrixMarket matrix coordinate pattern general\n3 3 3\n1 2\n2 3\n";
+    assert_eq!(Graph::<3>::from_matrix_market(short), Err(GraphFormatError::Parse { line: 4, message: String::from("expected 3 entries, found 2") }));
+    let long: &str = "%%MatrixMarket matrix coordinate pattern general\n3 3 1\n1 2\n2 3\n";
+    assert_eq!(Graph::<3>::from_matrix_market(long), Err(GraphFormatError::Parse { line: 4, message: String::from("expected 1 entries, found 2") }));
+}
+
+#[test]
+fn graphml_round_trip() {
+    let g: Graph<5> = Graph::star();
+    assert_eq!(Graph::from_graphml(&g.to_graphml()), Ok(g));
+
+    let h: Graph<3> = Graph::from_edges(&[(0, 1), (2, 1)]);
+    assert_eq!(Graph::from_graphml(&h.to_graphml()), Ok(h));
+
+    let text: &str = "<graphml>\n<graph edgedefault='directed'>\n<node id='a'/>\n<node id='b'/>\n<edge source='a' target='c'/>\n</graph>\n</graphml>\n";
+    let bad: Result<Graph<2>, GraphFormatError> = Graph::from_graphml(text);
+    assert_eq!(bad, Err(GraphFormatError::Parse { line: 5, message: String::from("unknown node 'c'") }));
+}
+
+#[test]
+fn maximum_flow() {
+    // the textbook network from Cormen et al., maximum flow 23
+    let g: Graph<6> = Graph::from([
+        [0,16,13,0,0,0],
+        [0,0,0,12,0,0],
+        [0,4,0,0,14,0],
+        [0,0,9,0,0,20],
+        [0,0,0,7,0,4],
+        [0,0,0,0,0,0]
+    ]);
+
+    for result in [g.edmonds_karp(0, 5), g.push_relabel(0, 5)] {
+        assert_eq!(result.value(), 23);
+
+        // capacity and conservation
+        let flow: Matrix<u8, 6, 6> = result.flow();
+        let capacity: Matrix<u8, 6, 6> = g.into();
+        for i in 0..6 {
+            for j in 0..6 {
+                assert!(flow[i][j] <= capacity[i][j]);
+            }
+        }
+        for v in 1..5 {
+            let inflow: u32 = (0..6).map(|u| u32::from(flow[u][v])).sum();
+            let outflow: u32 = (0..6).map(|w| u32::from(flow[v][w])).sum();
+            assert_eq!(inflow, outflow);
+        }
+
+        let cut: u64 = result.cut_edges().iter().map(|&(i, j)| u64::from(capacity[i][j])).sum();
+        assert_eq!(cut, 23);
+        assert!(result.source_side()[0]);
+        assert!(!result.source_side()[5]);
+    }
+}
+
+#[test]
+fn bipartite_matching() {
+    // left 0, 1, 2 and right 3, 4, 5, only a perfect matching uses 0-4
+    let g: Graph<6> = Graph::undirected_from_edges(&[(0, 3), (0, 4), (1, 3), (2, 3), (2, 5)]);
+    let matching: Vec<(usize, usize)> = g.maximum_matching().unwrap();
+    assert_eq!(matching.len(), 3);
+    assert!(matching.contains(&(0, 4)));
+
+    assert_eq!(Graph::<3>::complete().maximum_matching(), None);
+}
+
+#[test]
+fn minimum_spanning_trees() {
+    let g: WeightedGraph<u32, 4> = WeightedGraph::from(Matrix::from([
+        [0,1,4,3],
+        [1,0,2,0],
+        [4,2,0,5],
+        [3,0,5,0]
+    ]));
+
+    for tree in [g.kruskal(), g.prim()] {
+        // edges 0-1, 1-2 and 0-3, each stored both ways
+        assert_eq!(tree.total_weight(), 12);
+        assert_eq!(tree.weight(1, 2), Some(2));
+        assert_eq!(tree.weight(0, 3), Some(3));
+        assert_eq!(tree.weight(0, 2), None);
+    }
+}
+
+#[test]
+fn spanning_forest() {
+    let g: WeightedGraph<f64, 4> = WeightedGraph::from(Matrix::from([
+        [0.0,1.5,0.0,0.0],
+        [1.5,0.0,0.0,0.0],
+        [0.0,0.0,0.0,2.5],
+        [0.0,0.0,2.5,0.0]
+    ]));
+    assert_eq!(g.kruskal(), g);
+    assert_eq!(g.prim(), g);
+}
+
+#[test]
+fn spanning_tree_nan_weight() {
+    // a NaN weight sorts after every number, so the other two edges are taken
+    let g: WeightedGraph<f64, 3> = WeightedGraph::from([
+        [None, Some(1.0), Some(f64::NAN)],
+        [Some(1.0), None, Some(2.0)],
+        [Some(f64::NAN), Some(2.0), None]
+    ]);
+    let tree: WeightedGraph<f64, 3> = g.kruskal();
+    assert_eq!(tree.total_weight(), 6.0);
+    assert_eq!(tree.weight(0, 2), None);
+    assert_eq!(g.prim().total_weight(), 6.0);
+}
+
+#[test]
+fn complement_union_intersection() {
+    let path: Graph<4> = Graph::path();
+    let cycle: Graph<4> = Graph::cycle();
+    assert_eq!(path.union(&cycle), cycle);
+    assert_eq!(path.intersection(&cycle), path);
+    assert_eq!(Graph::<4>::empty().complement(), Graph::complete());
+    assert_eq!(cycle.complement(), Graph::undirected_from_edges(&[(0, 2), (1, 3)]));
+}
+
+#[test]
+fn line_graphs() {
+    // the line graph of a star is complete, and of a path is a shorter path
+    let star: Graph<4> = Graph::star();
+    assert_eq!(star.line_graph::<3>(), Graph::complete());
+    assert_eq!(Graph::<4>::path().line_graph::<3>(), Graph::path());
+
+    let directed: Graph<3> = Graph::from_edges(&[(0, 1), (1, 2)]);
+    assert_eq!(directed.line_graph::<2>(), Graph::from_edges(&[(0, 1)]));
+}
+
+#[test]
+fn graph_products() {
+    // P2 x P3 is the 2 x 3 grid
+    let p2: Graph<2> = Graph::path();
+    let p3: Graph<3> = Graph::path();
+    let grid: Graph<6> = p2.cartesian_product(&p3);
+    assert_eq!(grid, Graph::grid(2, 3));
+
+    // K2 x K2 as a tensor product is two disjoint edges
+    let k2: Graph<2> = Graph::complete();
+    let tensor: Graph<4> = k2.tensor_product(&k2);
+    assert_eq!(tensor, Graph::undirected_from_edges(&[(0, 3), (1, 2)]));
+
+    // self loops from both factors add up on the diagonal
+    let looped: Graph<1> = Graph::from([[1]]);
+    let doubled: Graph<1> = looped.cartesian_product(&looped);
+    assert_eq!(doubled, Graph::from([[2]]));
+}
+
+#[test]
+#[should_panic(expected = "too many parallel edges for u8")]
+fn tensor_product_overflow() {
+    let g: Graph<1> = Graph::from([[16]]);
+    let _: Graph<1> = g.tensor_product(&g);
+}
+
+fn relabel<const N: usize>(graph: Graph<N>, permutation: [usize; N]) -> Graph<N> {
+    let array: [[u8; N]; N] = graph.into();
+    let mut result: [[u8; N]; N] = [[0; N]; N];
+    for i in 0..N {
+        for j in 0..N {
+            result[permutation[i]][permutation[j]] = array[i][j];
+        }
+    }
+
+    Graph::from(result)
+}
+
+fn group_order<const N: usize>(generators: &[[usize; N]]) -> usize {
+    // closure of the generators under composition
+    let identity: [usize; N] = std::array::from_fn(|i| i);
+
+    let mut seen: std::collections::HashSet<[usize; N]> = std::collections::HashSet::from([identity]);
+    let mut stack: Vec<[usize; N]> = vec![identity];
+    while let Some(element) = stack.pop() {
+        for generator in generators {
+            let product: [usize; N] = std::array::from_fn(|i| generator[element[i]]);
+            if seen.insert(product) {
+                stack.push(product);
+            }
+        }
+    }
+
+    seen.len()
+}
+
+#[test]
+fn isomorphism_detection() {
+    let petersen: Graph<10> = Graph::petersen();
+    let shuffled: Graph<10> = relabel(petersen, [3, 7, 0, 9, 4, 1, 8, 2, 6, 5]);
+
+    let p: Matrix<u8, 10, 10> = petersen.isomorphism(&shuffled).unwrap();
+    let a: Matrix<u8, 10, 10> = petersen.into();
+    let b: Matrix<u8, 10, 10> = shuffled.into();
+    assert_eq!(p * a * p.transpose(), b);
+
+    // both 2-regular, but a hexagon is not two triangles
+    let hexagon: Graph<6> = Graph::cycle();
+    let triangles: Graph<6> = Graph::undirected_from_edges(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 5), (5, 3)]);
+    assert!(!hexagon.is_isomorphic(&triangles));
+
+    let out_star: Graph<3> = Graph::from_edges(&[(0, 1), (0, 2)]);
+    let in_star: Graph<3> = Graph::from_edges(&[(1, 0), (2, 0)]);
+    assert!(!out_star.is_isomorphic(&in_star));
+    assert!(in_star.is_isomorphic(&relabel(in_star, [2, 0, 1])));
+}
+
+#[test]
+fn graph_invariants() {
+    let star: Graph<4> = Graph::star();
+    assert_eq!(star.degree_sequence(), vec![(3, 3), (1, 1), (1, 1), (1, 1)]);
+
+    let spectrum = star.adjacency_spectrum();
+    assert!((spectrum[0] + 3.0_f64.sqrt()).abs() < 1e-12);
+    assert!((spectrum[3] - 3.0_f64.sqrt()).abs() < 1e-12);
+}
+
+#[test]
+fn canonical_forms() {
+    let g: Graph<6> = Graph::undirected_from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0), (0, 4), (4, 5)]);
+    let h: Graph<6> = relabel(g, [5, 2, 4, 0, 1, 3]);
+    assert_eq!(g.canonical_form(), h.canonical_form());
+    assert_ne!(g.canonical_form(), Graph::<6>::path().canonical_form());
+
+    let mut seen: std::collections::HashSet<Graph<6>> = std::collections::HashSet::new();
+    seen.insert(g.canonical_form());
+    assert!(seen.contains(&h.canonical_form()));
+
+    let labeling: [usize; 6] = g.canonical_labeling();
+    assert_eq!(relabel(g, labeling), g.canonical_form());
+}
+
+#[test]
+fn automorphism_groups() {
+    assert_eq!(group_order(&Graph::<10>::petersen().automorphism_generators()), 120);
+    assert_eq!(group_order(&Graph::<5>::cycle().automorphism_generators()), 10);
+    assert_eq!(group_order(&Graph::<6>::complete().automorphism_generators()), 720);
+    assert_eq!(group_order(&Graph::<8>::hypercube().automorphism_generators()), 48);
+    assert!(Graph::<3>::from_edges(&[(0, 1), (1, 2)]).automorphism_generators().is_empty());
+}
@@ -0,0 +1,94 @@
+use la::io::*;
+use la::matrix::*;
+
+#[test]
+fn matrix_market_coordinate() {
+    let text: &str = "%%MatrixMarket matrix coordinate real general\n% a comment\n2 3 3\n1 1 1.5\n2 3 -2\n1 2 4\n";
+    let matrix: Matrix<f64, 2, 3> = Matrix::from_matrix_market(text).unwrap();
+    assert_eq!(matrix, Matrix::from([[1.5, 4.0, 0.0], [0.0, 0.0, -2.0]]));
+
+    let symmetric: &str = "%%MatrixMarket matrix coordinate integer symmetric\n3 3 3\n1 1 5\n3 1 2\n3 2 7\n";
+    let matrix: Matrix<i32, 3, 3> = Matrix::from_matrix_market(symmetric).unwrap();
+    assert_eq!(matrix, Matrix::from([[5, 0, 2], [0, 0, 7], [2, 7, 0]]));
+
+    let pattern: &str = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+    let matrix: Matrix<u8, 2, 2> = Matrix::from_matrix_market(pattern).unwrap();
+    assert_eq!(matrix, Matrix::from([[0, 1], [1, 0]]));
+}
+
+#[test]
+fn matrix_market_array() {
+    // column major
+    let text: &str = "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n";
+    let matrix: Matrix<i32, 2, 2> = Matrix::from_matrix_market(text).unwrap();
+    assert_eq!(matrix, Matrix::from([[1, 2], [3, 4]]));
+
+    let symmetric: &str = "%%MatrixMarket matrix array real symmetric\n2 2\n1.0\n2.0\n3.0\n";
+    let matrix: Matrix<f64, 2, 2> = Matrix::from_matrix_market(symmetric).unwrap();
+    assert_eq!(matrix, Matrix::from([[1.0, 2.0], [2.0, 3.0]]));
+}
+
+#[test]
+fn matrix_market_round_trip() {
+    let matrix: Matrix<i32, 3, 3> = Matrix::from([[4, 1, 0], [1, 5, 2], [0, 2, 6]]);
+    let formats: [MatrixMarketFormat; 3] = [
+        MatrixMarketFormat::default(),
+        MatrixMarketFormat { layout: Layout::Array, field: Field::Integer, symmetry: Symmetry::General },
+        MatrixMarketFormat { layout: Layout::Coordinate, field: Field::Integer, symmetry: Symmetry::Symmetric }
+    ];
+    for format in formats {
+        let text: String = matrix.to_matrix_market(format);
+        assert_eq!(Matrix::<i32, 3, 3>::from_matrix_market(&text).unwrap(), matrix);
+    }
+
+    let text: String = matrix.to_matrix_market(MatrixMarketFormat { layout: Layout::Coordinate, field: Field::Integer, symmetry: Symmetry::Symmetric });
+    assert_eq!(text, "%%MatrixMarket matrix coordinate integer symmetric\n3 3 5\n1 1 4\n2 1 1\n2 2 5\n3 2 2\n3 3 6\n");
+}
+
+#[test]
+fn matrix_market_errors() {
+    let bad_value: &str = "%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 2 x7\n";
+    assert_eq!(Matrix::<i32, 2, 2>::from_matrix_market(bad_value), Err(MatrixFormatError::Parse { line: 3, column: 5, message: String::from("expected a number, found 'x7'") }));
+
+    let out_of_range: &str = "%%MatrixMarket matrix coordinate integer general\n2 2 1\n  3 1 7\n";
+    assert!(matches!(Matrix::<i32, 2, 2>::from_matrix_market(out_of_range), Err(MatrixFormatError::Parse { line: 3, column: 3, .. })));
+
+    let complex: &str = "%%MatrixMarket matrix coordinate complex general\n2 2 0\n";
+    assert!(matches!(Matrix::<i32, 2, 2>::from_matrix_market(complex), Err(MatrixFormatError::Parse { line: 1, column: 34, .. })));
+
+    let short: &str = "%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 1\n";
+    assert!(matches!(Matrix::<i32, 2, 2>::from_matrix_market(short), Err(MatrixFormatError::Parse { line: 3, .. })));
+
+    let size: &str = "%%MatrixMarket matrix array integer general\n2 1\n1\n2\n";
+    assert_eq!(Matrix::<i32, 2, 2>::from_matrix_market(size), Err(MatrixFormatError::SizeMismatch { expected: (2, 2), found: (2, 1) }));
+
+    // the size line is checked before anything is allocated for it
+    let huge: &str = "%%MatrixMarket matrix coordinate integer general\n4000000000 4000000000 1\n1 1 1\n";
+    assert_eq!(Matrix::<i32, 2, 2>::from_matrix_market(huge), Err(MatrixFormatError::SizeMismatch { expected: (2, 2), found: (4000000000, 4000000000) }));
+}
+
+#[test]
+fn delimited_text() {
+    let matrix: Matrix<f64, 2, 3> = Matrix::from([[1.0, 2.5, -3.0], [4.0, 0.0, 6.0]]);
+    let csv: String = matrix.to_csv(',', Some(&["x", "y", "z, w"]));
+    assert_eq!(csv, "x,y,\"z, w\"\n1,2.5,-3\n4,0,6\n");
+    assert_eq!(Matrix::<f64, 2, 3>::from_csv(&csv, ',', true).unwrap(), matrix);
+
+    let tsv: String = matrix.to_csv('\t', None);
+    assert_eq!(Matrix::<f64, 2, 3>::from_csv(&tsv, '\t', false).unwrap(), matrix);
+
+    let quoted: &str = "\"1\", \"2\"\n3, 4\n";
+    assert_eq!(Matrix::<i32, 2, 2>::from_csv(quoted, ',', false).unwrap(), Matrix::from([[1, 2], [3, 4]]));
+}
+
+#[test]
+fn delimited_errors() {
+    let bad: &str = "a,b\n1,2\n3, four\n";
+    assert_eq!(Matrix::<i32, 2, 2>::from_csv(bad, ',', true), Err(MatrixFormatError::Parse { line: 3, column: 4, message: String::from("expected a number, found 'four'") }));
+
+    let ragged: &str = "1,2\n3\n";
+    assert!(matches!(Matrix::<i32, 2, 2>::from_csv(ragged, ',', false), Err(MatrixFormatError::Parse { line: 2, .. })));
+
+    let tall: &str = "1,2\n3,4\n5,6\n";
+    assert_eq!(Matrix::<i32, 2, 2>::from_csv(tall, ',', false), Err(MatrixFormatError::SizeMismatch { expected: (2, 2), found: (3, 2) }));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// runs the la binary with the input on stdin, returning the exit code, stdout and stderr
+fn la(arguments: &[&str], input: &str) -> (i32, String, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_la"))
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    (output.status.code().unwrap(), String::from_utf8(output.stdout).unwrap(), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn matrix_commands() {
+    assert_eq!(la(&["det"], "1,2\n3,4\n"), (0, String::from("-2\n"), String::new()));
+    assert_eq!(la(&["det", "--precision", "6"], "0.1,0.2\n0.3,0.4\n").1, "-0.02\n");
+    assert_eq!(la(&["det", "--precision", "10", "-e", "[1e300]"], "").1, format!("{}\n", 1e300));
+    assert_eq!(la(&["det", "--precision", "400", "-e", "[0.5]"], "").1, "0.5\n");
+    assert_eq!(la(&["rank", "-"], "1\t2\n2\t4\n").1, "1\n");
+    assert_eq!(la(&["transpose", "-e", "[1 2 3]"], "").1, "[1]\n[2]\n[3]\n");
+    assert_eq!(la(&["inverse", "-e", "[2 0; 0 4]", "--output", "json"], "").1, "[[0.5,0.0],[0.0,0.25]]\n");
+    assert_eq!(la(&["rref", "-e", "[1 2; 2 4]", "--output", "latex"], "").1, "\\begin{bmatrix}\n1 & 2 \\\\\n0 & 0\n\\end{bmatrix}\n");
+
+    let mtx: &str = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 3\n2 2 5\n";
+    assert_eq!(la(&["det"], mtx).1, "15\n");
+
+    // without --rhs the last column is the right hand side
+    assert_eq!(la(&["solve", "-e", "[2 0 4; 0 1 3]"], "").1, "2\n3\n");
+    let rhs = std::env::temp_dir().join(format!("la-rhs-{}.csv", std::process::id()));
+    std::fs::write(&rhs, "4\n3\n").unwrap();
+    let solved = la(&["solve", "-e", "[2 0; 0 1]", "--rhs", rhs.to_str().unwrap()], "");
+    std::fs::remove_file(&rhs).unwrap();
+    assert_eq!(solved.1, "2\n3\n");
+}
+
+#[test]
+fn eigenvalues() {
+    // repeated eigenvalues of a Jordan block stay real and exact
+    assert_eq!(la(&["eigen", "-e", "[1 1 0; 0 1 1; 0 0 1]"], "").1, "1\n1\n1\n");
+    assert_eq!(la(&["eigen", "-e", "[2 1; 0 2]"], "").1, "2\n2\n");
+    assert_eq!(la(&["eigen", "-e", "[0 -1; 1 0]", "--precision", "9"], "").1, "0 - 1i\n0 + 1i\n");
+    assert_eq!(la(&["eigen", "-e", "[0 -1; 1 0]", "--output", "json", "--precision", "9"], "").1, "[{\"im\":-1.0,\"re\":0.0},{\"im\":1.0,\"re\":0.0}]\n");
+    assert_eq!(la(&["eigen", "-e", "[2 0; 0 3]"], "").1, "values:\n2\n3\nvectors:\n[1  0]\n[0  1]\n");
+}
+
+#[test]
+fn polynomials() {
+    assert_eq!(la(&["roots", "-e", "x^2 - 3x + 2", "--precision", "9"], "").1, "1\n2\n");
+    assert_eq!(la(&["derivative"], "x^3 + 2x\n").1, "3x^2 + 2\n");
+    assert_eq!(la(&["derivative", "--output", "json"], "x^3 + 2x").1, "[2.0,0.0,3.0]\n");
+}
+
+#[test]
+fn errors() {
+    // NaN entries give NaN results rather than a panic
+    assert_eq!(la(&["det"], "NaN,1\n1,1\n"), (0, String::from("NaN\n"), String::new()));
+    assert_eq!(la(&["rank"], "NaN,1\n1,1\n").0, 0);
+
+    assert_eq!(la(&["det", "-e", "[1 2 3]"], ""), (1, String::new(), String::from("la: det needs a square matrix, found 1x3\n")));
+    assert_eq!(la(&["inverse", "-e", "[1 2; 2 4]"], "").2, "la: the matrix is singular\n");
+    assert_eq!(la(&["roots", "-e", "x^99999999999"], "").0, 1);
+    assert_eq!(la(&["det"], "%%MatrixMarket matrix coordinate real general\n4000000000 4000000000 1\n1 1 1\n").0, 1);
+
+    let (code, _, message) = la(&["frobnicate"], "");
+    assert_eq!(code, 2);
+    assert!(message.starts_with("la: unknown command 'frobnicate'\n\nusage: la <command>"));
+    assert_eq!(la(&["det", "--output", "yaml"], "").0, 2);
+    assert_eq!(la(&["--help"], "").0, 0);
+}
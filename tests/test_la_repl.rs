@@ -0,0 +1,78 @@
+#![cfg(feature = "repl")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+// feeds the lines to la-repl on stdin and returns stdout without the banner, and stderr,
+// HOME points at a scratch directory so the history file stays out of the way
+fn repl(lines: &str) -> (String, String) {
+    let home = std::env::temp_dir().join(format!("la-repl-{}-{}", std::process::id(), RUNS.fetch_add(1, Ordering::Relaxed)));
+    std::fs::create_dir_all(&home).unwrap();
+    let mut child = Command::new(env!("CARGO_BIN_EXE_la-repl"))
+        .env("HOME", &home)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(lines.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+    std::fs::remove_dir_all(&home).unwrap();
+
+    assert!(output.status.success());
+    let stdout: String = String::from_utf8(output.stdout).unwrap();
+    let (banner, rest) = stdout.split_once('\n').unwrap();
+    assert!(banner.starts_with("la "));
+    (String::from(rest), String::from_utf8(output.stderr).unwrap())
+}
+
+#[test]
+fn precedence() {
+    assert_eq!(repl("-2^2\n").0, "ans = -4\n");
+    assert_eq!(repl("2^3^2\n").0, "ans = 512\n");
+    assert_eq!(repl("2^-1\n").0, "ans = 0.5\n");
+    assert_eq!(repl("1 + 2 * 3 - 4 / 2\n").0, "ans = 5\n");
+    assert_eq!(repl("(1 + 2) * 3\n").0, "ans = 9\n");
+    assert_eq!(repl("0.1 + 0.2\n").0, "ans = 0.3\n");
+
+    // transpose binds tighter than anything else, \ like * and /
+    assert_eq!(repl("A = [1 2; 3 4];\nA'\n").0, "ans =\n[1  3]\n[2  4]\n");
+    assert_eq!(repl("A = [1 2; 3 4];\n-A'\n").0, "ans =\n[-1  -3]\n[-2  -4]\n");
+    assert_eq!(repl("A = [2 0; 0 4];\nb = [2; 4];\nA \\ b\n").0, "ans =\n[1]\n[1]\n");
+    assert_eq!(repl("A = [2 0; 0 4];\nb = [2; 4];\nA \\ b * 2\n").0, "ans =\n[2]\n[2]\n");
+    assert_eq!(repl("A = [2 0; 0 4];\nA^-1 * A\n").0, "ans =\n[1  0]\n[0  1]\n");
+    assert_eq!(repl("[1; 2]' * [3; 4]\n").0, "ans =\n[11]\n");
+}
+
+#[test]
+fn variables_and_functions() {
+    assert_eq!(repl("x = 3\n2 * x\nans + x\n").0, "x = 3\nans = 6\nans = 9\n");
+    assert_eq!(repl("x = 3;\nvars\nclear x\nvars\n").0, "  x        a scalar\n");
+    assert_eq!(repl("rank([1 2; 2 4])\ndet([1 2; 3 4])\ntrace(eye(3))\n").0, "ans = 1\nans = -2\nans = 3\n");
+    assert_eq!(repl("ones(2, 1) + zeros(2, 1)\n").0, "ans =\n[1]\n[1]\n");
+    assert_eq!(repl("eig([2 1; 0 2])\n").0, "ans =\n[2]\n[2]\n");
+    assert_eq!(repl("reduce([1 2; 2 4])\n").0, "ans =\n[1  2]\n[0  0]\n");
+    assert_eq!(repl("1e300\n").0, format!("ans = {}\n", 1e300));
+    assert_eq!(repl("1 + 1\n!1\n").0, "ans = 2\n1 + 1\nans = 2\n");
+    assert!(repl("help rank\n").0.starts_with("rank(A) is the number of pivots"));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(repl("y + 1\n").1, "  y + 1\n  ^\nerror: 'y' is not defined\n");
+    assert_eq!(repl("[1 2] + [1; 2]\n").1, "  [1 2] + [1; 2]\n  ^^^^^^^^^^^^^^\nerror: cannot add a 1x2 matrix and a 2x1 matrix\n");
+    assert_eq!(repl("eig([0 -1; 1 0])\n").1.lines().last().unwrap(), "error: the eigenvalues are complex, for example 0 - 1i");
+    assert_eq!(repl("inv([1 2; 2 4])\n").1.lines().last().unwrap(), "error: the matrix is singular");
+
+    // sizes and powers that could not be built are errors, not aborts
+    assert_eq!(repl("zeros(5000, 5000)\n").1.lines().last().unwrap(), "error: a 5000x5000 matrix is too large, the limit is 16777216 entries");
+    assert_eq!(repl("zeros(1e10, 1e10)\n").1.lines().last().unwrap(), "error: zeros needs a whole number size up to 16777216");
+    assert_eq!(repl("eye(200000)\n").1.lines().last().unwrap(), "error: a 200000x200000 matrix is too large, the limit is 16777216 entries");
+    assert_eq!(repl("ones(1e30)\n").1, "  ones(1e30)\n       ^^^^\nerror: ones needs a whole number size up to 16777216\n");
+    assert_eq!(repl("[1 1; 0 1]^5e9\n").1.lines().last().unwrap(), "error: the power 5000000000 is too large, matrix powers go up to 4294967295");
+    assert_eq!(repl("[1 1; 0 1]^0.5\n").1.lines().last().unwrap(), "error: matrices can only be raised to whole number powers");
+    assert_eq!(repl("!7\n").1, "error: no line 7 in this session's history\n");
+}
@@ -0,0 +1,126 @@
+use la::markov::*;
+use la::matrix::*;
+use la::vector::*;
+use la::graph::*;
+
+fn close(left: f64, right: f64) -> bool {
+    (left - right).abs() < 1e-9
+}
+
+fn gamblers_ruin() -> MarkovChain<5> {
+    // fair coin, stop at 0 or 4
+    MarkovChain::new(Matrix::from([
+        [1.0, 0.0, 0.0, 0.0, 0.0],
+        [0.5, 0.0, 0.5, 0.0, 0.0],
+        [0.0, 0.5, 0.0, 0.5, 0.0],
+        [0.0, 0.0, 0.5, 0.0, 0.5],
+        [0.0, 0.0, 0.0, 0.0, 1.0]
+    ])).unwrap()
+}
+
+#[test]
+fn stochastic_validation() {
+    let short: Matrix<f64, 2, 2> = Matrix::from([[0.5, 0.4], [0.0, 1.0]]);
+    assert!(matches!(MarkovChain::new(short), Err(StochasticError::RowSum { row: 0, .. })));
+
+    let negative: Matrix<f64, 2, 2> = Matrix::from([[1.0, 0.0], [1.5, -0.5]]);
+    assert_eq!(MarkovChain::new(negative), Err(StochasticError::NegativeEntry { row: 1, column: 1 }));
+
+    let nan: Matrix<f64, 2, 2> = Matrix::from([[1.0, 0.0], [f64::NAN, 1.0]]);
+    assert_eq!(MarkovChain::new(nan), Err(StochasticError::NegativeEntry { row: 1, column: 0 }));
+    let infinite: Matrix<f64, 2, 2> = Matrix::from([[f64::INFINITY, 0.0], [0.0, 1.0]]);
+    assert!(matches!(MarkovChain::new(infinite), Err(StochasticError::RowSum { row: 0, .. })));
+
+    let valid: Matrix<f64, 2, 2> = Matrix::from([[0.9, 0.1], [0.5, 0.5]]);
+    assert!(MarkovChain::try_from(valid).is_ok());
+}
+
+#[test]
+fn n_step_distributions() {
+    let chain: MarkovChain<2> = MarkovChain::new(Matrix::from([[0.9, 0.1], [0.5, 0.5]])).unwrap();
+    let two_steps: Matrix<f64, 2, 2> = chain.n_step(2);
+    assert!(close(two_steps[0][0], 0.86));
+    assert!(close(two_steps[1][1], 0.3));
+
+    let after: Vector<f64, 2> = chain.distribution_after(Vector::from([1.0, 0.0]), 2);
+    assert!(close(after[0], 0.86));
+    assert!(close(after[1], 0.14));
+}
+
+#[test]
+fn stationary_distribution() {
+    let chain: MarkovChain<2> = MarkovChain::new(Matrix::from([[0.9, 0.1], [0.5, 0.5]])).unwrap();
+    let pi: Vector<f64, 2> = chain.stationary_distribution().unwrap();
+    assert!(close(pi[0], 5.0 / 6.0));
+    assert!(close(pi[1], 1.0 / 6.0));
+
+    // two absorbing states, so two extreme stationary distributions and no unique one
+    let ruin: MarkovChain<5> = gamblers_ruin();
+    assert_eq!(ruin.stationary_distributions(), vec![Vector::from([1.0, 0.0, 0.0, 0.0, 0.0]), Vector::from([0.0, 0.0, 0.0, 0.0, 1.0])]);
+    assert_eq!(ruin.stationary_distribution(), None);
+}
+
+#[test]
+fn communicating_classes() {
+    let ruin: MarkovChain<5> = gamblers_ruin();
+    let classes: Vec<CommunicatingClass> = ruin.communicating_classes();
+    assert_eq!(classes.len(), 3);
+    assert_eq!(classes[1], CommunicatingClass { states: vec![1, 2, 3], closed: false, period: 2 });
+    assert!(classes[0].closed && classes[2].closed);
+    assert!(!ruin.is_irreducible());
+
+    let rotation: MarkovChain<3> = MarkovChain::random_walk(&Graph::from_edges(&[(0, 1), (1, 2), (2, 0)]));
+    assert!(rotation.is_irreducible());
+    assert_eq!(rotation.communicating_classes()[0].period, 3);
+
+    let lazy: MarkovChain<2> = MarkovChain::new(Matrix::from([[0.5, 0.5], [1.0, 0.0]])).unwrap();
+    assert_eq!(lazy.communicating_classes()[0].period, 1);
+}
+
+#[test]
+fn absorbing_chains() {
+    let absorption: Absorption<5> = gamblers_ruin().absorption().unwrap();
+    assert_eq!(absorption.absorbing_states(), vec![0, 4]);
+
+    // i (4 - i) expected steps, ruin with probability 1 - i / 4
+    let steps: Vector<f64, 5> = absorption.expected_steps();
+    for i in 0..5 {
+        assert!(close(steps[i], (i * (4 - i)) as f64));
+    }
+
+    let probabilities: Matrix<f64, 5, 5> = absorption.probabilities();
+    for i in 0..5 {
+        assert!(close(probabilities[i][4], i as f64 / 4.0));
+        assert!(close(probabilities[i][0], 1.0 - (i as f64 / 4.0)));
+    }
+
+    assert!(close(absorption.fundamental_matrix()[2][2], 2.0));
+
+    // state 1 is stuck flipping with 2 and never reaches the absorbing state 0
+    let trapped: MarkovChain<3> = MarkovChain::new(Matrix::from([[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]])).unwrap();
+    assert!(trapped.absorption().is_none());
+
+    // a diagonal within rounding of 1 is absorbing, the same tolerance new allows
+    let rounded: MarkovChain<2> = MarkovChain::new(Matrix::from([[1.0 - 1e-12, 1e-12], [0.5, 0.5]])).unwrap();
+    assert!(rounded.is_absorbing_state(0));
+    assert!(!rounded.is_absorbing_state(1));
+}
+
+#[test]
+fn pagerank() {
+    let cycle: Graph<4> = Graph::from_edges(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+    let rank: Vector<f64, 4> = cycle.pagerank(0.85);
+    for i in 0..4 {
+        assert!(close(rank[i], 0.25));
+    }
+
+    // everyone links to 0, which links nowhere
+    let star: Graph<4> = Graph::from_edges(&[(1, 0), (2, 0), (3, 0)]);
+    let rank: Vector<f64, 4> = star.pagerank(0.85);
+    assert!(close(rank.iter().sum(), 1.0));
+    assert!(rank[0] > rank[1]);
+    assert!(close(rank[1], rank[2]) && close(rank[2], rank[3]));
+
+    let empty: Graph<0> = Graph::from_edges(&[]);
+    assert_eq!(empty.pagerank(0.85), Vector::from([]));
+}
@@ -39,6 +39,24 @@ fn matrix_multiplication() {
     assert_eq!((a * b), c);
 }
 
+#[test]
+fn rectangular_multiplication() {
+    let a: Matrix<i32, 2, 3> = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+    let b: Matrix<i32, 3, 4> = Matrix::from([[1, 0, 0, 1], [0, 1, 0, 1], [0, 0, 1, 1]]);
+    let c: Matrix<i32, 2, 4> = Matrix::from([[1, 2, 3, 6], [4, 5, 6, 15]]);
+    assert_eq!((a * b), c);
+
+    // large enough to cross the tile edges in every direction
+    let a: Matrix<i64, 70, 130> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| ((i * 7 + j * 3) % 11) as i64 - 5)));
+    let b: Matrix<i64, 130, 90> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| ((i * 5 + j * 2) % 13) as i64 - 6)));
+    let c: Matrix<i64, 70, 90> = a * b;
+    for i in 0..70 {
+        for j in 0..90 {
+            assert_eq!(c[i][j], (0..130).map(|k| a[i][k] * b[k][j]).sum::<i64>());
+        }
+    }
+}
+
 #[test]
 fn vector_matrix_multiplication() {
 	let a: Matrix<u8, 2, 2> = Matrix::from([[1,2],[3,4]]);
@@ -84,4 +102,53 @@ fn outer_product() {
     let v: Vector<usize, 3> = Vector::from([1,2,3]);
     let a: Matrix<usize, 3, 3>= Matrix::from([[1,2,3],[2,4,6],[3,6,9]]);
     assert_eq!(outer(v, v), a);
-}
\ No newline at end of file
+}
+#[test]
+fn vandermonde_matrix() {
+    let v: Vector<i32, 3> = Vector::from([1,2,3]);
+    let a: Matrix<i32, 3, 3> = Matrix::from([[1,1,1],[1,2,4],[1,3,9]]);
+    assert_eq!(vandermonde(v), a);
+}
+
+#[test]
+fn exponentiation() {
+    let a: Matrix<u64, 2, 2> = Matrix::from([[1,1],[1,0]]);
+    let b: Matrix<u64, 2, 2> = Matrix::from([[89,55],[55,34]]);
+    assert_eq!(a.pow(10), b);
+    assert_eq!(a.pow(0), identity());
+}
+
+#[test]
+fn determinant() {
+    let a: Matrix<i64, 3, 3> = Matrix::from([[2,0,1],[1,3,2],[1,1,2]]);
+    assert_eq!(a.determinant(), 6);
+
+    let b: Matrix<i64, 3, 3> = Matrix::from([[0,1,2],[1,0,3],[4,-3,8]]);
+    assert_eq!(b.determinant(), -2);
+
+    let c: Matrix<f64, 2, 2> = Matrix::from([[1.0,2.0],[2.0,4.0]]);
+    assert_eq!(c.determinant(), 0.0);
+}
+
+#[test]
+fn symmetric_eigenvalues() {
+    let a: Matrix<f64, 3, 3> = Matrix::from([[2.0,-1.0,0.0],[-1.0,2.0,-1.0],[0.0,-1.0,2.0]]);
+    let (values, vectors) = symmetric_eigen(a);
+    let expected: [f64; 3] = [2.0 - 2.0_f64.sqrt(), 2.0, 2.0 + 2.0_f64.sqrt()];
+    for k in 0..3 {
+        assert!((values[k] - expected[k]).abs() < 1e-12);
+
+        // A v = lambda v
+        let v: Vector<f64, 3> = vectors.get_column(k);
+        let residual: Vector<f64, 3> = (a * v) - (v * values[k]);
+        assert!((residual * residual) < 1e-20);
+    }
+}
+
+#[test]
+fn kronecker_product() {
+    let a: Matrix<i32, 2, 2> = Matrix::from([[1,2],[3,4]]);
+    let b: Matrix<i32, 1, 2> = Matrix::from([[0,1]]);
+    let c: Matrix<i32, 2, 4> = Matrix::from([[0,1,0,2],[0,3,0,4]]);
+    assert_eq!(kronecker(a, b), c);
+}
@@ -0,0 +1,89 @@
+use la::npy::*;
+use la::matrix::*;
+use la::vector::*;
+
+fn npy(version: u8, header: &str, data: &[u8]) -> Vec<u8> {
+    // what numpy.save writes, without the padding it adds
+    let mut result: Vec<u8> = b"\x93NUMPY".to_vec();
+    result.extend([version, 0]);
+    match version {
+        1 => result.extend((header.len() as u16).to_le_bytes()),
+        _ => result.extend((header.len() as u32).to_le_bytes())
+    }
+    result.extend(header.as_bytes());
+    result.extend(data);
+    result
+}
+
+#[test]
+fn reading_orders_and_endianness() {
+    let data: Vec<u8> = [1.0_f64, 2.0, 3.0, 4.0, 5.0, 6.0].iter().flat_map(|x| x.to_le_bytes()).collect();
+    let c_order: Vec<u8> = npy(1, "{'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }\n", &data);
+    let matrix: Matrix<f64, 2, 3> = Matrix::from_npy(&c_order).unwrap();
+    assert_eq!(matrix, Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]));
+
+    let fortran_order: Vec<u8> = npy(1, "{'descr': '<f8', 'fortran_order': True, 'shape': (2, 3), }\n", &data);
+    let matrix: Matrix<f64, 2, 3> = Matrix::from_npy(&fortran_order).unwrap();
+    assert_eq!(matrix, Matrix::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]));
+
+    let data: Vec<u8> = [7_i32, -8, 9].iter().flat_map(|x| x.to_be_bytes()).collect();
+    let big_endian: Vec<u8> = npy(2, "{'descr': '>i4', 'fortran_order': False, 'shape': (3,), }\n", &data);
+    let vector: Vector<i32, 3> = Vector::from_npy(&big_endian).unwrap();
+    assert_eq!(vector, Vector::from([7, -8, 9]));
+
+    let bytes: Vec<u8> = npy(1, "{'descr': '|u1', 'fortran_order': False, 'shape': (1, 2), }\n", &[3, 250]);
+    assert_eq!(Matrix::<u8, 1, 2>::from_npy(&bytes).unwrap(), Matrix::from([[3, 250]]));
+}
+
+#[test]
+fn writing() {
+    let matrix: Matrix<i64, 2, 2> = Matrix::from([[1, -2], [3, 4]]);
+    let bytes: Vec<u8> = matrix.to_npy();
+    assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+
+    let header_length: usize = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+    assert_eq!((10 + header_length) % 64, 0);
+    let header: &str = std::str::from_utf8(&bytes[10..(10 + header_length)]).unwrap();
+    assert!(header.starts_with("{'descr': '<i8', 'fortran_order': False, 'shape': (2, 2), }"));
+    assert!(header.ends_with('\n'));
+    assert_eq!(Matrix::<i64, 2, 2>::from_npy(&bytes).unwrap(), matrix);
+
+    let vector: Vector<f32, 3> = Vector::from([0.5, -1.0, 2.0]);
+    let bytes: Vec<u8> = vector.to_npy();
+    assert!(std::str::from_utf8(&bytes[10..74]).unwrap().contains("'shape': (3,)"));
+    assert_eq!(Vector::<f32, 3>::from_npy(&bytes).unwrap(), vector);
+}
+
+#[test]
+fn errors() {
+    let matrix: Matrix<f64, 2, 3> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let bytes: Vec<u8> = matrix.to_npy();
+    assert_eq!(Matrix::<f64, 3, 2>::from_npy(&bytes), Err(NpyError::ShapeMismatch { expected: vec![3, 2], found: vec![2, 3] }));
+    assert_eq!(Vector::<f64, 6>::from_npy(&bytes), Err(NpyError::ShapeMismatch { expected: vec![6], found: vec![2, 3] }));
+    assert_eq!(Matrix::<i64, 2, 3>::from_npy(&bytes), Err(NpyError::Dtype { expected: String::from("<i8"), found: String::from("<f8") }));
+
+    assert!(matches!(Matrix::<f64, 2, 3>::from_npy(&bytes[..60]), Err(NpyError::Format(_))));
+    assert!(matches!(Matrix::<f64, 2, 3>::from_npy(&bytes[..bytes.len() - 1]), Err(NpyError::Format(_))));
+    assert!(matches!(Matrix::<f64, 2, 3>::from_npy(b"not an npy file"), Err(NpyError::Format(_))));
+
+    // shapes whose element or byte count overflows
+    for shape in ["(4294967296, 4294967296)", "(3074457345618258603,)"] {
+        let header: String = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}", shape);
+        assert!(matches!(Vector::<f64, 2>::from_npy(&npy(1, &header, &[0; 16])), Err(NpyError::Format(_))));
+    }
+}
+
+#[cfg(feature = "npz")]
+#[test]
+fn archives() {
+    let matrix: Matrix<f64, 2, 2> = Matrix::from([[1.0, 2.0], [3.0, 4.0]]);
+    let vector: Vector<i32, 3> = Vector::from([5, 6, 7]);
+    let archive: Vec<u8> = write_npz(&[("weights", matrix.to_npy()), ("bias", vector.to_npy())]);
+
+    let arrays = read_npz(&archive).unwrap();
+    assert_eq!(arrays.keys().collect::<Vec<&String>>(), vec!["bias", "weights"]);
+    assert_eq!(Matrix::<f64, 2, 2>::from_npy(&arrays["weights"]).unwrap(), matrix);
+    assert_eq!(Vector::<i32, 3>::from_npy(&arrays["bias"]).unwrap(), vector);
+
+    assert!(read_npz(b"not a zip archive").is_err());
+}
@@ -0,0 +1,73 @@
+use la::polynomial::*;
+use la::orthogonal::*;
+
+#[test]
+fn chebyshev_polynomials() {
+	let t: Polynomial<i32> = Polynomial::from(vec![0,-3,0,4]);
+	let u: Polynomial<i32> = Polynomial::from(vec![0,-4,0,8]);
+	assert_eq!(chebyshev(3), t);
+	assert_eq!(chebyshev_second(3), u);
+}
+
+#[test]
+fn legendre_polynomials() {
+	let p: Polynomial<f64> = Polynomial::from(vec![0.0, -1.5, 0.0, 2.5]);
+	assert_eq!(legendre(3), p);
+}
+
+#[test]
+fn hermite_polynomials() {
+	let h: Polynomial<i32> = Polynomial::from(vec![0,-12,0,8]);
+	assert_eq!(hermite(3), h);
+}
+
+#[test]
+fn laguerre_polynomials() {
+	let l: Polynomial<f64> = Polynomial::from(vec![1.0, -2.0, 0.5]);
+	assert_eq!(laguerre(2), l);
+}
+
+#[test]
+fn tridiagonal_eigenvalues() {
+	// [[2, 1], [1, 2]] has eigenvalues 1 and 3
+	let (values, vectors): (Vec<f64>, Vec<Vec<f64>>) = tridiagonal_eigen(&[2.0, 2.0], &[1.0]);
+	assert!((values[0] - 1.0).abs() < 1e-12);
+	assert!((values[1] - 3.0).abs() < 1e-12);
+	assert!((vectors[1][0].abs() - 0.5_f64.sqrt()).abs() < 1e-12);
+
+	// non-finite entries give NaN instead of iterating until the convergence check fails
+	let (values, vectors): (Vec<f64>, Vec<Vec<f64>>) = tridiagonal_eigen(&[1.0, f64::NAN], &[0.0]);
+	assert!(values.iter().chain(vectors.iter().flatten()).all(|x| x.is_nan()));
+	assert!(tridiagonal_eigen(&[1.0, 2.0], &[f64::INFINITY]).0.iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn gauss_quadrature() {
+	// an n point rule is exact up to degree 2n - 1
+	let rule: Quadrature<f64> = gauss_legendre(3);
+	assert!((rule.integrate(|x| x.powi(4)) - 0.4).abs() < 1e-12);
+	assert!((rule.nodes[2] - 0.6_f64.sqrt()).abs() < 1e-12);
+
+	let rule: Quadrature<f64> = gauss_hermite(4);
+	assert!((rule.integrate(|x| x * x) - std::f64::consts::PI.sqrt() / 2.0).abs() < 1e-12);
+
+	let rule: Quadrature<f64> = gauss_laguerre(4);
+	assert!((rule.integrate(|x| x.powi(3)) - 6.0).abs() < 1e-10);
+
+	let rule: Quadrature<f64> = gauss_chebyshev(3);
+	assert!((rule.integrate(|x| x * x) - std::f64::consts::PI / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn chebyshev_series() {
+	// the series reproduces a polynomial of low enough degree exactly
+	let p: Polynomial<f64> = Polynomial::from(vec![1.0, -2.0, 3.0]);
+	let approximation: Polynomial<f64> = chebyshev_approximation(|x| p.at(x), 4, 0.0, 2.0);
+	for i in 0..approximation.len() {
+		let expected: f64 = if i < p.len() { p[i] } else { 0.0 };
+		assert!((approximation[i] - expected).abs() < 1e-10);
+	}
+
+	let approximation: Polynomial<f64> = chebyshev_approximation(f64::exp, 10, -1.0, 1.0);
+	assert!((approximation.at(0.5) - 0.5_f64.exp()).abs() < 1e-9);
+}
@@ -0,0 +1,62 @@
+use la::parallel::*;
+use la::dynamic::*;
+use la::matrix::*;
+
+// every test drops the threshold to zero, with the rayon feature that sends everything through
+// the thread pool and the results have to match the plain loops exactly
+
+fn sample(rows: usize, columns: usize, seed: usize) -> DynMatrix<f64> {
+    let data: Vec<f64> = (0..(rows * columns)).map(|x| ((x * 7 + seed) % 23) as f64 / 4.0 - 2.5).collect();
+    DynMatrix::new(rows, columns, data)
+}
+
+#[test]
+fn multiplication() {
+    set_threshold(0);
+    let (a, b): (DynMatrix<f64>, DynMatrix<f64>) = (sample(150, 97, 1), sample(97, 130, 2));
+    let product: DynMatrix<f64> = &a * &b;
+    for i in 0..150 {
+        for j in 0..130 {
+            let expected: f64 = (0..97).fold(0.0, |sum, k| sum + a[i][k] * b[k][j]);
+            assert_eq!(product[i][j], expected);
+        }
+    }
+
+    let a: Matrix<i64, 80, 70> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| (i * 3 + j) as i64 % 9 - 4)));
+    let b: Matrix<i64, 70, 90> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| (i + j * 5) as i64 % 7 - 3)));
+    let c: Matrix<i64, 80, 90> = a * b;
+    assert_eq!(DynMatrix::from(c), &DynMatrix::from(a) * &DynMatrix::from(b));
+}
+
+#[test]
+fn entrywise() {
+    set_threshold(0);
+    let (a, b): (DynMatrix<f64>, DynMatrix<f64>) = (sample(40, 30, 3), sample(40, 30, 4));
+    let (sum, difference, scaled): (DynMatrix<f64>, DynMatrix<f64>, DynMatrix<f64>) = (&a + &b, &a - &b, &a * 3.0);
+    for i in 0..40 {
+        for j in 0..30 {
+            assert_eq!(sum[i][j], a[i][j] + b[i][j]);
+            assert_eq!(difference[i][j], a[i][j] - b[i][j]);
+            assert_eq!(scaled[i][j], a[i][j] * 3.0);
+        }
+    }
+
+    let m: Matrix<i32, 3, 2> = Matrix::from([[1, 2], [3, 4], [5, 6]]);
+    assert_eq!(madd(m, m), mscale(2, m));
+    assert_eq!(msub(m, m), Matrix::from([[0; 2]; 3]));
+}
+
+#[test]
+fn elimination() {
+    set_threshold(0);
+    let a: DynMatrix<f64> = &sample(60, 60, 5) + &(&DynMatrix::identity(60) * 10.0);
+    let b: DynMatrix<f64> = sample(60, 3, 6);
+    let x: DynMatrix<f64> = a.solve_columns(&b).unwrap();
+    let residual: DynMatrix<f64> = &(&a * &x) - &b;
+    assert!((0..60).all(|i| residual[i].iter().all(|r| r.abs() < 1e-9)));
+
+    let singular: Matrix<f64, 3, 3> = Matrix::from([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [1.0, 0.0, 1.0]]);
+    assert_eq!(singular.rank(), 2);
+    assert_eq!(singular.reduce(), Matrix::from([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0], [0.0, 0.0, 0.0]]));
+    assert_eq!(DynMatrix::from(singular).rank(), 2);
+}
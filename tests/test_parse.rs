@@ -0,0 +1,89 @@
+use la::parse::*;
+use la::matrix::*;
+use la::vector::*;
+use la::polynomial::*;
+
+#[test]
+fn matrix_forms() {
+    let expected: Matrix<i32, 2, 2> = Matrix::from([[1, 2], [3, 4]]);
+    assert_eq!("[1 2; 3 4]".parse::<Matrix<i32, 2, 2>>().unwrap(), expected);
+    assert_eq!("[1, 2; 3, 4]".parse::<Matrix<i32, 2, 2>>().unwrap(), expected);
+    assert_eq!("[[1,2],[3,4]]".parse::<Matrix<i32, 2, 2>>().unwrap(), expected);
+    assert_eq!("\n[[1, 2],\n [3, 4]]\n".parse::<Matrix<i32, 2, 2>>().unwrap(), expected);
+    assert_eq!("[1 2\n 3 4\n]".parse::<Matrix<i32, 2, 2>>().unwrap(), expected);
+    assert_eq!("[-1.5 2e3]".parse::<Matrix<f64, 1, 2>>().unwrap(), Matrix::from([[-1.5, 2000.0]]));
+}
+
+#[test]
+fn vector_forms() {
+    let expected: Vector<i32, 3> = Vector::from([1, -2, 3]);
+    assert_eq!("[1 -2 3]".parse::<Vector<i32, 3>>().unwrap(), expected);
+    assert_eq!("[1; -2; 3]".parse::<Vector<i32, 3>>().unwrap(), expected);
+    assert_eq!("[[1], [-2], [3]]".parse::<Vector<i32, 3>>().unwrap(), expected);
+
+    let error: ParseError = "[1 2; 3 4]".parse::<Vector<i32, 4>>().unwrap_err();
+    assert_eq!(error.message, "expected a single row or a single column");
+}
+
+#[test]
+fn matrix_errors() {
+    let error: ParseError = "[1 2; 3 x]".parse::<Matrix<i32, 2, 2>>().unwrap_err();
+    assert_eq!(error, ParseError { span: 8..9, message: String::from("invalid number 'x'") });
+
+    let error: ParseError = "[1 2; 3]".parse::<Matrix<i32, 2, 2>>().unwrap_err();
+    assert_eq!(error, ParseError { span: 6..7, message: String::from("expected 2 entries in row 2, found 1") });
+
+    let error: ParseError = "[[1, 2], [3, 4, 5]]".parse::<Matrix<i32, 2, 2>>().unwrap_err();
+    assert_eq!(error.span, 9..18);
+
+    let error: ParseError = "[1 2; 3 4]".parse::<Matrix<i32, 3, 2>>().unwrap_err();
+    assert_eq!(error, ParseError { span: 0..10, message: String::from("expected 3 rows, found 2") });
+
+    assert_eq!("[1 2".parse::<Matrix<i32, 1, 2>>().unwrap_err().span, 4..4);
+    assert_eq!("[1 2] 3".parse::<Matrix<i32, 1, 2>>().unwrap_err().span, 6..7);
+    assert_eq!("1 2".parse::<Matrix<i32, 1, 2>>().unwrap_err().span, 0..1);
+    assert_eq!("[1,,2]".parse::<Matrix<i32, 1, 2>>().unwrap_err().span, 3..4);
+    assert_eq!("[[1 2]]".parse::<Matrix<i32, 1, 2>>().unwrap_err().span, 4..5);
+    assert_eq!(format!("{}", "[1 y]".parse::<Matrix<i32, 1, 2>>().unwrap_err()), "3..4: invalid number 'y'");
+}
+
+#[test]
+fn parse_rows_spans() {
+    let rows: Vec<Row<i32>> = parse_rows("[1 2 3; 40 5]").unwrap();
+    assert_eq!(rows, vec![(1..6, vec![1, 2, 3]), (8..12, vec![40, 5])]);
+}
+
+#[test]
+fn polynomials() {
+    assert_eq!("3x^2 - 2x + 1".parse::<Polynomial<i32>>().unwrap(), Polynomial::from(vec![1, -2, 3]));
+    assert_eq!("-x^3+x".parse::<Polynomial<i32>>().unwrap(), Polynomial::from(vec![0, 1, 0, -1]));
+    assert_eq!("2*t + t^2 + 3t".parse::<Polynomial<i32>>().unwrap(), Polynomial::from(vec![0, 5, 1]));
+    assert_eq!("0.5x - 0.25".parse::<Polynomial<f64>>().unwrap(), Polynomial::from(vec![-0.25, 0.5]));
+    assert_eq!("1e-300x^2 + 2E3".parse::<Polynomial<f64>>().unwrap(), Polynomial::from(vec![2000.0, 0.0, 1e-300]));
+    assert_eq!("2e - 3 + e^2".parse::<Polynomial<f64>>().unwrap(), Polynomial::from(vec![-3.0, 2.0, 1.0]));
+    assert_eq!("7".parse::<Polynomial<i32>>().unwrap(), Polynomial::from(vec![7]));
+    assert_eq!("x - x".parse::<Polynomial<i32>>().unwrap(), Polynomial::from(vec![]));
+
+    // round trips through Display
+    let polynomial: Polynomial<i32> = Polynomial::from(vec![4, 0, -1, 2]);
+    assert_eq!(polynomial.to_string().parse::<Polynomial<i32>>().unwrap(), polynomial);
+}
+
+#[test]
+fn polynomial_errors() {
+    assert_eq!("3x^2 2x".parse::<Polynomial<i32>>().unwrap_err(), ParseError { span: 5..6, message: String::from("expected '+' or '-'") });
+    assert_eq!("x + y".parse::<Polynomial<i32>>().unwrap_err().span, 4..5);
+    assert_eq!("x^".parse::<Polynomial<i32>>().unwrap_err().span, 2..2);
+    assert_eq!("1.5x".parse::<Polynomial<i32>>().unwrap_err(), ParseError { span: 0..3, message: String::from("invalid number '1.5'") });
+    assert_eq!("x + ".parse::<Polynomial<i32>>().unwrap_err().message, "expected a term");
+    assert_eq!("".parse::<Polynomial<i32>>().unwrap_err().message, "expected a polynomial");
+    assert_eq!("x^99999999999".parse::<Polynomial<i32>>().unwrap_err(), ParseError { span: 2..13, message: format!("the power is above the maximum of {}", MAX_DEGREE) });
+    assert_eq!("x^99999999999999999999999".parse::<Polynomial<i32>>().unwrap_err().span, 2..25);
+
+    // unsigned types have no negative coefficients and sums that do not fit are errors, not overflows
+    assert_eq!("-x + 1".parse::<Polynomial<u32>>().unwrap_err(), ParseError { span: 0..1, message: String::from("invalid number '-1'") });
+    assert_eq!("x^2 - 3".parse::<Polynomial<u32>>().unwrap_err(), ParseError { span: 4..7, message: String::from("invalid number '-3'") });
+    assert_eq!("200 + 100".parse::<Polynomial<u8>>().unwrap_err(), ParseError { span: 4..9, message: String::from("the coefficients of power 0 add up to more than fits") });
+    assert_eq!("-100x - 100x".parse::<Polynomial<i8>>().unwrap_err().span, 6..12);
+    assert_eq!("-128 + 100 + 27".parse::<Polynomial<i8>>().unwrap(), Polynomial::from(vec![-1]));
+}
@@ -1,4 +1,6 @@
 use la::polynomial::*;
+use la::matrix::*;
+use num::complex::Complex;
 
 #[test]
 fn addition() {
@@ -53,4 +55,192 @@ fn integration() {
 	let a: Polynomial<u8> = Polynomial::from(vec![1,2]);
     let b: Polynomial<u8> = Polynomial::from(vec![0,1,1]);
 	assert_eq!(a.integral(), b);
-}
\ No newline at end of file
+}
+#[test]
+fn interpolation() {
+	let xs: Vec<f64> = vec![0.0, 1.0, 2.0];
+	let ys: Vec<f64> = vec![1.0, 3.0, 7.0];
+	let a: Polynomial<f64> = Polynomial::from(vec![1.0, 1.0, 1.0]);
+	assert_eq!(Polynomial::interpolate(&xs, &ys), a);
+}
+
+#[test]
+fn hermite_interpolation() {
+	// x^3 through f(0) = 0, f'(0) = 0, f(1) = 1, f'(1) = 3
+	let xs: Vec<f64> = vec![0.0, 1.0];
+	let values: Vec<Vec<f64>> = vec![vec![0.0, 0.0], vec![1.0, 3.0]];
+	let a: Polynomial<f64> = Polynomial::from(vec![0.0, 0.0, 0.0, 1.0]);
+	assert_eq!(Polynomial::hermite_interpolate(&xs, &values), a);
+}
+
+#[test]
+fn least_squares_fit() {
+	let xs: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0];
+	let ys: Vec<f64> = vec![1.0, 3.0, 5.0, 7.0];
+	let fit: Polynomial<f64> = Polynomial::fit(&xs, &ys, 1);
+	assert!((fit[0] - 1.0).abs() < 1e-12);
+	assert!((fit[1] - 2.0).abs() < 1e-12);
+
+	// degree 8 on [0, 10], where the normal equations square an already large condition number
+	let p: Polynomial<f64> = Polynomial::from(vec![1.0, -2.0, 0.5, 3.0, -1.0, 0.25, -0.5, 0.125, 0.01]);
+	let xs: Vec<f64> = (0..30).map(|i| i as f64 / 3.0).collect();
+	let ys: Vec<f64> = xs.iter().map(|&x| p.eval(x)).collect();
+	let fit: Polynomial<f64> = Polynomial::fit(&xs, &ys, 8);
+	let scale: f64 = ys.iter().fold(0.0, |max, y| y.abs().max(max));
+	assert!(xs.iter().zip(&ys).all(|(&x, &y)| (fit.eval(x) - y).abs() < 1e-10 * scale));
+	assert!((0..=8).all(|k| (fit[k] - p[k]).abs() < 1e-6));
+}
+
+#[test]
+fn normalization() {
+	let a: Polynomial<i8> = Polynomial::from(vec![1,2,3]);
+	let b: Polynomial<i8> = Polynomial::from(vec![0,2,3]);
+	let c: Polynomial<i8> = a - b;
+	assert_eq!(c.len(), 1);
+	assert_eq!(c.degree(), Some(0));
+	assert_eq!(c, Polynomial::from(vec![1,0,0]));
+
+	let zero: Polynomial<i8> = Polynomial::from(vec![0,0]);
+	assert!(zero.is_zero());
+	assert_eq!(zero.degree(), None);
+	assert_eq!(zero.leading_coefficient(), None);
+}
+
+#[test]
+fn trailing_zero_equality() {
+	let mut a: Polynomial<u8> = Polynomial::from(vec![1]);
+	a.push(0);
+	assert_eq!(a, Polynomial::from(vec![1]));
+	assert_eq!(a.degree(), Some(0));
+}
+
+#[test]
+fn monic() {
+	let a: Polynomial<f64> = Polynomial::from(vec![1.0, 2.0, 4.0]);
+	let b: Polynomial<f64> = Polynomial::from(vec![0.25, 0.5, 1.0]);
+	assert!(!a.is_monic());
+	assert_eq!(a.leading_coefficient(), Some(4.0));
+	assert_eq!(a.to_monic(), b);
+	assert!(b.is_monic());
+}
+
+#[test]
+fn multiplication() {
+	let a: Polynomial<i32> = Polynomial::from(vec![1,1]);
+	let b: Polynomial<i32> = Polynomial::from(vec![-1,1]);
+	let c: Polynomial<i32> = Polynomial::from(vec![-1,0,1]);
+	assert_eq!((a * b), c);
+}
+
+#[test]
+fn long_division() {
+	let a: Polynomial<i32> = Polynomial::from(vec![-1,0,1]);
+	let b: Polynomial<i32> = Polynomial::from(vec![1,1]);
+	let (q, r) = pdivrem(a, b);
+	assert_eq!(q, Polynomial::from(vec![-1,1]));
+	assert!(r.is_zero());
+
+	// a divisor that is not monic is fine while every step divides exactly
+	let a: Polynomial<i32> = Polynomial::from(vec![1,3,2]);
+	let b: Polynomial<i32> = Polynomial::from(vec![1,2]);
+	assert_eq!(pdivrem(a, b), (Polynomial::from(vec![1,1]), Polynomial::from(vec![])));
+
+	let a: Polynomial<f64> = Polynomial::from(vec![1.0, 0.0, 1.0]);
+	let b: Polynomial<f64> = Polynomial::from(vec![1.0, 2.0]);
+	assert_eq!(pdivrem(a, b), (Polynomial::from(vec![-0.25, 0.5]), Polynomial::from(vec![1.25])));
+}
+
+#[test]
+#[should_panic(expected = "integer divisors should be monic")]
+fn inexact_integer_division() {
+	let a: Polynomial<i32> = Polynomial::from(vec![1,0,1]);
+	let b: Polynomial<i32> = Polynomial::from(vec![1,2]);
+	let _ = pdivrem(a, b);
+}
+
+#[test]
+fn generic_evaluation() {
+	let a: Polynomial<f64> = Polynomial::from(vec![1.0, 0.0, 1.0]);
+	assert_eq!(a.eval(Complex::new(0.0, 1.0)), Complex::new(0.0, 0.0));
+
+	// x^2 - 1 at a matrix that squares to the identity
+	let b: Polynomial<i32> = Polynomial::from(vec![-1,0,1]);
+	let m: Matrix<i32, 2, 2> = Matrix::from([[0,1],[1,0]]);
+	assert_eq!(b.eval(m), zero());
+}
+
+#[test]
+fn multipoint_evaluation() {
+	let a: Polynomial<i64> = Polynomial::from(vec![1,-2,0,3]);
+	let xs: Vec<i64> = vec![-2,-1,0,1,2,3,4];
+	assert_eq!(a.multipoint(&xs), a.at_many(&xs));
+}
+
+#[test]
+fn evaluation_error_bound() {
+	let a: Polynomial<f64> = Polynomial::from(vec![-1.0, 3.0, -3.0, 1.0]);
+	let x: f64 = 1.0 + 1e-5;
+	let (y, error) = a.at_with_error(x);
+	assert!((y - 1e-15).abs() <= error);
+}
+
+#[test]
+fn composition() {
+	let p: Polynomial<i32> = Polynomial::from(vec![1,0,1]);
+	let q: Polynomial<i32> = Polynomial::from(vec![1,2]);
+	let c: Polynomial<i32> = Polynomial::from(vec![2,4,4]);
+	assert_eq!(compose(p, q), c);
+}
+
+#[test]
+fn taylor_shift() {
+	let p: Polynomial<i32> = Polynomial::from(vec![0,0,1]);
+	let q: Polynomial<i32> = Polynomial::from(vec![9,6,1]);
+	assert_eq!(p.shift(3), q);
+	assert_eq!(p.shift(3), compose(p.clone(), Polynomial::from(vec![3,1])));
+}
+
+#[test]
+fn argument_scaling() {
+	let p: Polynomial<i32> = Polynomial::from(vec![1,1,1]);
+	let q: Polynomial<i32> = Polynomial::from(vec![1,2,4]);
+	assert_eq!(p.scale_arg(2), q);
+}
+
+#[test]
+fn higher_derivatives() {
+	let a: Polynomial<u32> = Polynomial::from(vec![1,1,1,1]);
+	assert_eq!(a.nth_derivative(2), Polynomial::from(vec![2,6]));
+	assert_eq!(a.nth_derivative(2), a.derivative().derivative());
+	assert!(a.nth_derivative(4).is_zero());
+}
+
+#[test]
+fn integration_constants() {
+	let a: Polynomial<f64> = Polynomial::from(vec![0.0, 2.0]);
+	assert_eq!(a.integral_with_constant(3.0), Polynomial::from(vec![3.0, 0.0, 1.0]));
+	assert_eq!(a.definite_integral(1.0, 3.0), 8.0);
+}
+
+#[test]
+fn roots() {
+	let close = |a: Complex<f64>, b: Complex<f64>| (a - b).norm() < 1e-9;
+
+	let p: Polynomial<f64> = Polynomial::from(vec![2.0, -3.0, 1.0]);
+	let roots: Vec<Complex<f64>> = p.roots();
+	assert_eq!(roots.len(), 2);
+	assert!(close(roots[0], Complex::new(1.0, 0.0)) && close(roots[1], Complex::new(2.0, 0.0)));
+	assert_eq!(roots[0].im, 0.0);
+
+	let p: Polynomial<f64> = Polynomial::from(vec![1.0, 0.0, 1.0]);
+	let roots: Vec<Complex<f64>> = p.roots();
+	assert!(close(roots[0], Complex::new(0.0, -1.0)) && close(roots[1], Complex::new(0.0, 1.0)));
+
+	// x^3 - x^2 has a double root at zero
+	let p: Polynomial<f64> = Polynomial::from(vec![0.0, 0.0, -1.0, 1.0]);
+	assert_eq!(p.roots(), vec![Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)]);
+
+	// a NaN coefficient gives NaN roots rather than a panic while sorting them
+	let p: Polynomial<f64> = Polynomial::from(vec![f64::NAN, 1.0, 1.0]);
+	assert_eq!(p.roots().len(), 2);
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "serde")]
+
+use serde::{Serialize, Deserialize};
+
+use la::vector::*;
+use la::matrix::*;
+use la::polynomial::*;
+use la::graph::*;
+
+#[test]
+fn round_trips() {
+    let vector: Vector<i32, 3> = Vector::from([1, 2, 3]);
+    assert_eq!(serde_json::to_string(&vector).unwrap(), "[1,2,3]");
+    assert_eq!(serde_json::from_str::<Vector<i32, 3>>("[1,2,3]").unwrap(), vector);
+
+    let matrix: Matrix<f64, 2, 3> = Matrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    let json: String = serde_json::to_string(&matrix).unwrap();
+    assert_eq!(json, "[[1.0,2.0,3.0],[4.0,5.0,6.0]]");
+    assert_eq!(serde_json::from_str::<Matrix<f64, 2, 3>>(&json).unwrap(), matrix);
+
+    let polynomial: Polynomial<i32> = Polynomial::from(vec![1, 0, 2, 0]);
+    assert_eq!(serde_json::to_string(&polynomial).unwrap(), "[1,0,2]");
+    assert_eq!(serde_json::from_str::<Polynomial<i32>>("[1,0,2,0]").unwrap(), polynomial);
+
+    let graph: Graph<3> = Graph::from_edges(&[(0, 1), (1, 2), (1, 2)]);
+    assert_eq!(serde_json::to_string(&graph).unwrap(), "[[0,1,0],[0,0,2],[0,0,0]]");
+    assert_eq!(serde_json::from_str::<Graph<3>>("[[0,1,0],[0,0,2],[0,0,0]]").unwrap(), graph);
+}
+
+#[test]
+fn dimension_errors() {
+    let error: String = serde_json::from_str::<Vector<i32, 3>>("[1,2]").unwrap_err().to_string();
+    assert!(error.contains("expected a vector with 3 entries, found 2"), "{}", error);
+
+    let error: String = serde_json::from_str::<Matrix<i32, 2, 2>>("[[1,2],[3,4],[5,6]]").unwrap_err().to_string();
+    assert!(error.contains("expected a matrix with 2 rows, found 3"), "{}", error);
+
+    let error: String = serde_json::from_str::<Matrix<i32, 2, 2>>("[[1,2],[3]]").unwrap_err().to_string();
+    assert!(error.contains("expected 2 columns in row 1 of a matrix, found 1"), "{}", error);
+
+    assert!(serde_json::from_str::<Graph<2>>("[[0,1,0],[1,0,0]]").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "la::serialize::flat")]
+    transform: Matrix<i32, 2, 3>,
+    nested: Matrix<i32, 1, 2>
+}
+
+#[test]
+fn flat_layout() {
+    let config: Config = Config { transform: Matrix::from([[1, 2, 3], [4, 5, 6]]), nested: Matrix::from([[7, 8]]) };
+    let json: String = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"transform":{"rows":2,"cols":3,"data":[1,2,3,4,5,6]},"nested":[[7,8]]}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+    let error: String = serde_json::from_str::<Config>(r#"{"transform":{"rows":3,"cols":2,"data":[1,2,3,4,5,6]},"nested":[[7,8]]}"#).unwrap_err().to_string();
+    assert!(error.contains("expected a 2x3 matrix, found 3x2"), "{}", error);
+
+    let error: String = serde_json::from_str::<Config>(r#"{"transform":{"rows":2,"cols":3,"data":[1,2,3]},"nested":[[7,8]]}"#).unwrap_err().to_string();
+    assert!(error.contains("expected 6 entries for a 2x3 matrix, found 3"), "{}", error);
+}
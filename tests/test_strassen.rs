@@ -0,0 +1,122 @@
+use std::ops;
+
+use la::matrix::*;
+use la::dynamic::*;
+use num::{Num, Zero, One};
+use num::rational::Ratio;
+
+// the integers mod a prime P, the kind of exact field Strassen is meant for
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Gf<const P: u64>(u64);
+impl<const P: u64> Gf<P> {
+    fn new(value: i64) -> Self {
+        Gf(value.rem_euclid(P as i64) as u64)
+    }
+
+    fn inverse(self) -> Self {
+        // Fermat, a^(P-2) = a^-1
+        let mut result: Gf<P> = Gf(1);
+        for _ in 0..(P - 2) {
+            result = result * self;
+        }
+        result
+    }
+}
+impl<const P: u64> ops::Add for Gf<P> {
+    type Output = Self;
+    fn add(self, right: Self) -> Self {
+        Gf((self.0 + right.0) % P)
+    }
+}
+impl<const P: u64> ops::Sub for Gf<P> {
+    type Output = Self;
+    fn sub(self, right: Self) -> Self {
+        Gf((self.0 + P - right.0) % P)
+    }
+}
+impl<const P: u64> ops::Mul for Gf<P> {
+    type Output = Self;
+    fn mul(self, right: Self) -> Self {
+        Gf((self.0 * right.0) % P)
+    }
+}
+impl<const P: u64> ops::Div for Gf<P> {
+    type Output = Self;
+    fn div(self, right: Self) -> Self {
+        ops::Mul::mul(self, right.inverse())
+    }
+}
+impl<const P: u64> ops::Rem for Gf<P> {
+    type Output = Self;
+    fn rem(self, _: Self) -> Self {
+        Gf(0) // every nonzero element divides exactly
+    }
+}
+impl<const P: u64> Zero for Gf<P> {
+    fn zero() -> Self {
+        Gf(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+impl<const P: u64> One for Gf<P> {
+    fn one() -> Self {
+        Gf(1)
+    }
+}
+impl<const P: u64> Num for Gf<P> {
+    type FromStrRadixErr = std::num::ParseIntError;
+    fn from_str_radix(text: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i64::from_str_radix(text, radix).map(Gf::new)
+    }
+}
+
+fn naive<F: Num + Copy>(left: &DynMatrix<F>, right: &DynMatrix<F>) -> DynMatrix<F> {
+    let n: usize = left.rows();
+    let mut result: DynMatrix<F> = DynMatrix::zeros(n, n);
+    for i in 0..n {
+        for j in 0..n {
+            result[i][j] = (0..n).fold(F::zero(), |sum, k| sum + left[i][k] * right[k][j]);
+        }
+    }
+    result
+}
+
+fn sample<F: Num + Copy>(n: usize, seed: i64, entry: impl Fn(i64) -> F) -> DynMatrix<F> {
+    let data: Vec<F> = (0..(n * n) as i64).map(|x| entry((x * 17 + seed * 5) % 19 - 9)).collect();
+    DynMatrix::new(n, n, data)
+}
+
+#[test]
+fn integers() {
+    let a: Matrix<i64, 64, 64> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| ((i * 13 + j * 7) % 21) as i64 - 10)));
+    let b: Matrix<i64, 64, 64> = Matrix::from(std::array::from_fn(|i| std::array::from_fn(|j| ((i * 3 + j * 11) % 17) as i64 - 8)));
+    assert_eq!(strassen(a, b, 8), mmul(a, b));
+    assert_eq!(strassen(a, b, 64), mmul(a, b));
+
+    // odd sizes get padded at every level
+    for n in [1, 2, 3, 13, 31] {
+        let (a, b): (DynMatrix<i64>, DynMatrix<i64>) = (sample(n, 1, |x| x), sample(n, 2, |x| x));
+        assert_eq!(a.strassen(&b, 2), naive(&a, &b), "n = {}", n);
+        assert_eq!(a.strassen(&b, 0), naive(&a, &b), "n = {}", n);
+    }
+}
+
+#[test]
+fn rationals() {
+    let a: DynMatrix<Ratio<i64>> = sample(12, 3, |x| Ratio::new(x, 3));
+    let b: DynMatrix<Ratio<i64>> = sample(12, 4, |x| Ratio::new(1, x.abs() + 1));
+    assert_eq!(a.strassen(&b, 2), naive(&a, &b));
+}
+
+#[test]
+fn finite_fields() {
+    let a: DynMatrix<Gf<7>> = sample(40, 5, Gf::new);
+    let b: DynMatrix<Gf<7>> = sample(40, 6, Gf::new);
+    assert_eq!(a.strassen(&b, 4), naive(&a, &b));
+    assert_eq!(&a * &b, naive(&a, &b));
+
+    let x: Gf<7> = Gf::new(3);
+    assert_eq!(x * x.inverse(), Gf::one());
+}